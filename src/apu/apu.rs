@@ -0,0 +1,435 @@
+use crate::{
+    apu::{noise::NoiseChannel, square::SquareChannel, wave::WaveChannel},
+    audio::vgm::VgmLogger,
+    common::{
+        address::{
+            AUDIO, NOISE_REGISTERS, NR50, NR51, NR52, SQUARE1_REGISTERS, SQUARE2_REGISTERS,
+            WAVE_RAM, WAVE_REGISTERS,
+        },
+        constant::{CPU_FREQUENCY, DEFAULT_BYTE, DEFAULT_SAMPLE_RATE, FRAME_SEQUENCER_PERIOD},
+        types::{Address, Byte, Cycles, Size},
+    },
+};
+use std::{io, path::Path};
+
+pub const CHANNEL_COUNT: Size = 4;
+
+pub struct APU {
+    square1: SquareChannel,
+    square2: SquareChannel,
+    wave: WaveChannel,
+    noise: NoiseChannel,
+    enabled: bool,
+    nr50: Byte,
+    nr51: Byte,
+    frame_sequencer_cycles: Cycles,
+    frame_sequencer_step: Byte,
+    sample_rate: u32,
+    sample_cycles: u64,
+    left_sum: f32,
+    right_sum: f32,
+    sum_count: u32,
+    high_pass_factor: f32,
+    left_capacitor: f32,
+    right_capacitor: f32,
+    samples: Vec<f32>,
+    channel_sums: [f32; CHANNEL_COUNT],
+    channel_capacitors: [f32; CHANNEL_COUNT],
+    channel_samples: [Vec<f32>; CHANNEL_COUNT],
+    muted: [bool; CHANNEL_COUNT],
+    soloed: [bool; CHANNEL_COUNT],
+    registers: [Byte; AUDIO.size],
+    vgm_logger: Option<VgmLogger>,
+}
+
+impl APU {
+    pub fn new() -> Self {
+        APU {
+            square1: SquareChannel::new(true),
+            square2: SquareChannel::new(false),
+            wave: WaveChannel::new(),
+            noise: NoiseChannel::new(),
+            enabled: false,
+            nr50: DEFAULT_BYTE,
+            nr51: DEFAULT_BYTE,
+            frame_sequencer_cycles: 0,
+            frame_sequencer_step: DEFAULT_BYTE,
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            sample_cycles: 0,
+            left_sum: 0.0,
+            right_sum: 0.0,
+            sum_count: 0,
+            high_pass_factor: APU::compute_high_pass_factor(DEFAULT_SAMPLE_RATE),
+            left_capacitor: 0.0,
+            right_capacitor: 0.0,
+            samples: Vec::new(),
+            channel_sums: [0.0; CHANNEL_COUNT],
+            channel_capacitors: [0.0; CHANNEL_COUNT],
+            channel_samples: Default::default(),
+            muted: [false; CHANNEL_COUNT],
+            soloed: [false; CHANNEL_COUNT],
+            registers: [DEFAULT_BYTE; AUDIO.size],
+            vgm_logger: None,
+        }
+    }
+
+    pub fn get_sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = sample_rate;
+        self.sample_cycles = 0;
+        self.high_pass_factor = APU::compute_high_pass_factor(sample_rate);
+        self.samples.clear();
+        for samples in &mut self.channel_samples {
+            samples.clear();
+        }
+    }
+
+    fn compute_high_pass_factor(sample_rate: u32) -> f32 {
+        // Models the output capacitor that removes the DC offset of the DACs
+        0.999958_f32.powf(CPU_FREQUENCY as f32 / sample_rate as f32)
+    }
+
+    /// Returns the interleaved stereo samples produced since the last call, in [-1, 1].
+    pub fn take_samples(&mut self) -> Vec<f32> {
+        std::mem::take(&mut self.samples)
+    }
+
+    /// Returns the mono output of each channel produced since the last call, before mixing.
+    pub fn take_channel_samples(&mut self) -> [Vec<f32>; CHANNEL_COUNT] {
+        std::mem::take(&mut self.channel_samples)
+    }
+
+    pub fn get_square1(&self) -> &SquareChannel {
+        &self.square1
+    }
+
+    pub fn get_square2(&self) -> &SquareChannel {
+        &self.square2
+    }
+
+    pub fn get_wave(&self) -> &WaveChannel {
+        &self.wave
+    }
+
+    pub fn get_noise(&self) -> &NoiseChannel {
+        &self.noise
+    }
+
+    pub fn set_channel_muted(&mut self, channel: usize, muted: bool) {
+        self.muted[channel] = muted;
+    }
+
+    pub fn set_channel_soloed(&mut self, channel: usize, soloed: bool) {
+        self.soloed[channel] = soloed;
+    }
+
+    /// Soloed channels silence every other one; otherwise only muted channels are silent.
+    pub fn is_channel_audible(&self, channel: usize) -> bool {
+        if self.soloed.contains(&true) {
+            self.soloed[channel]
+        } else {
+            !self.muted[channel]
+        }
+    }
+
+    pub fn read_register(&self, address: Address) -> Byte {
+        if address >= SQUARE1_REGISTERS.start && address <= SQUARE1_REGISTERS.end {
+            self.square1
+                .read_register(address - SQUARE1_REGISTERS.start)
+        } else if address >= SQUARE2_REGISTERS.start && address <= SQUARE2_REGISTERS.end {
+            self.square2
+                .read_register(address - SQUARE2_REGISTERS.start)
+        } else if address >= WAVE_REGISTERS.start && address <= WAVE_REGISTERS.end {
+            self.wave.read_register(address - WAVE_REGISTERS.start)
+        } else if address >= NOISE_REGISTERS.start && address <= NOISE_REGISTERS.end {
+            self.noise.read_register(address - NOISE_REGISTERS.start)
+        } else if address == NR50.offset {
+            self.nr50
+        } else if address == NR51.offset {
+            self.nr51
+        } else if address == NR52.offset {
+            self.read_nr52()
+        } else if address >= WAVE_RAM.start && address <= WAVE_RAM.end {
+            self.wave.read_wave_ram(address - WAVE_RAM.start)
+        } else {
+            0xFF
+        }
+    }
+
+    pub fn write_register(&mut self, address: Address, value: Byte) {
+        // FF27-FF2F are unmapped, and only NR52 and the wave RAM accept writes while powered off
+        let is_register: bool = address <= NR52.offset || address >= WAVE_RAM.start;
+        let is_accepted: bool = self.enabled || address == NR52.offset || address >= WAVE_RAM.start;
+        if is_register && is_accepted {
+            self.log_vgm_write(address, value);
+            self.registers[address - AUDIO.start] = value;
+        }
+
+        if address >= WAVE_RAM.start && address <= WAVE_RAM.end {
+            self.wave.write_wave_ram(address - WAVE_RAM.start, value);
+        } else if address == NR52.offset {
+            self.write_nr52(value);
+        } else if !self.enabled {
+            // Every other register is read-only while the APU is powered off
+        } else if address >= SQUARE1_REGISTERS.start && address <= SQUARE1_REGISTERS.end {
+            self.square1
+                .write_register(address - SQUARE1_REGISTERS.start, value);
+        } else if address >= SQUARE2_REGISTERS.start && address <= SQUARE2_REGISTERS.end {
+            self.square2
+                .write_register(address - SQUARE2_REGISTERS.start, value);
+        } else if address >= WAVE_REGISTERS.start && address <= WAVE_REGISTERS.end {
+            self.wave
+                .write_register(address - WAVE_REGISTERS.start, value);
+        } else if address >= NOISE_REGISTERS.start && address <= NOISE_REGISTERS.end {
+            self.noise
+                .write_register(address - NOISE_REGISTERS.start, value);
+        } else if address == NR50.offset {
+            self.nr50 = value;
+        } else if address == NR51.offset {
+            self.nr51 = value;
+        }
+    }
+
+    pub fn start_vgm_logging(&mut self, path: &Path) -> io::Result<()> {
+        self.stop_vgm_logging();
+        let mut logger: VgmLogger = VgmLogger::create(path)?;
+        self.write_vgm_state(&mut logger)?;
+        self.vgm_logger = Some(logger);
+        Ok(())
+    }
+
+    pub fn stop_vgm_logging(&mut self) {
+        if let Some(mut logger) = self.vgm_logger.take() {
+            if let Err(error) = logger.finish() {
+                eprintln!(
+                    "Impossible de terminer l'export VGM {} : {}",
+                    logger.get_path().display(),
+                    error
+                );
+            }
+        }
+    }
+
+    /// Replays the current register state so a log started mid-song sounds right.
+    fn write_vgm_state(&self, logger: &mut VgmLogger) -> io::Result<()> {
+        logger.write_register(NR52.offset, (self.enabled as Byte) << 7)?;
+        for address in WAVE_RAM.start..=WAVE_RAM.end {
+            logger.write_register(address, self.registers[address - AUDIO.start])?;
+        }
+
+        // NRx4 registers retrigger the channels that are currently playing
+        let controls: [(Address, bool); CHANNEL_COUNT] = [
+            (SQUARE1_REGISTERS.end, self.square1.is_enabled()),
+            (SQUARE2_REGISTERS.end, self.square2.is_enabled()),
+            (WAVE_REGISTERS.end, self.wave.is_enabled()),
+            (NOISE_REGISTERS.end, self.noise.is_enabled()),
+        ];
+        for address in AUDIO.start..NR52.offset {
+            let mut value: Byte = self.registers[address - AUDIO.start];
+            if let Some((_, playing)) = controls.iter().find(|(control, _)| *control == address) {
+                value = (value & 0x7F) | ((*playing as Byte) << 7);
+            }
+            logger.write_register(address, value)?;
+        }
+        Ok(())
+    }
+
+    fn log_vgm_write(&mut self, address: Address, value: Byte) {
+        if let Some(logger) = &mut self.vgm_logger {
+            if let Err(error) = logger.write_register(address, value) {
+                eprintln!(
+                    "Impossible d'écrire l'export VGM {} : {}",
+                    logger.get_path().display(),
+                    error
+                );
+                self.vgm_logger = None;
+            }
+        }
+    }
+
+    fn read_nr52(&self) -> Byte {
+        ((self.enabled as Byte) << 7)
+            | 0x70
+            | ((self.noise.is_enabled() as Byte) << 3)
+            | ((self.wave.is_enabled() as Byte) << 2)
+            | ((self.square2.is_enabled() as Byte) << 1)
+            | (self.square1.is_enabled() as Byte)
+    }
+
+    fn write_nr52(&mut self, value: Byte) {
+        let enabled: bool = (value & 0x80) != 0;
+        if self.enabled && !enabled {
+            // Powering off clears every register but the wave RAM
+            self.registers[..WAVE_RAM.start - AUDIO.start].fill(DEFAULT_BYTE);
+            self.square1 = SquareChannel::new(true);
+            self.square2 = SquareChannel::new(false);
+            self.wave = WaveChannel::with_wave_ram(*self.wave.get_wave_ram());
+            self.noise = NoiseChannel::new();
+            self.nr50 = DEFAULT_BYTE;
+            self.nr51 = DEFAULT_BYTE;
+        } else if !self.enabled && enabled {
+            self.frame_sequencer_step = 0;
+            self.frame_sequencer_cycles = 0;
+        }
+        self.enabled = enabled;
+    }
+
+    pub fn tick(&mut self, cycles: Cycles) {
+        if let Some(logger) = &mut self.vgm_logger {
+            logger.advance(cycles);
+        }
+
+        for _ in 0..cycles / 4 {
+            self.step(4);
+        }
+    }
+
+    fn step(&mut self, cycles: Cycles) {
+        if self.enabled {
+            self.frame_sequencer_cycles += cycles;
+            if self.frame_sequencer_cycles >= FRAME_SEQUENCER_PERIOD {
+                self.frame_sequencer_cycles -= FRAME_SEQUENCER_PERIOD;
+                self.clock_frame_sequencer();
+            }
+
+            self.square1.tick(cycles);
+            self.square2.tick(cycles);
+            self.wave.tick(cycles);
+            self.noise.tick(cycles);
+        }
+
+        let outputs: [f32; CHANNEL_COUNT] = self.get_channel_outputs();
+        let (left, right): (f32, f32) = self.mix(&outputs);
+        self.left_sum += left;
+        self.right_sum += right;
+        for (sum, output) in self.channel_sums.iter_mut().zip(outputs) {
+            *sum += output;
+        }
+        self.sum_count += 1;
+
+        self.sample_cycles += cycles as u64 * self.sample_rate as u64;
+        if self.sample_cycles >= CPU_FREQUENCY as u64 {
+            self.sample_cycles -= CPU_FREQUENCY as u64;
+            self.push_sample();
+        }
+    }
+
+    fn clock_frame_sequencer(&mut self) {
+        // Lengths run at 256 Hz, the sweep at 128 Hz and envelopes at 64 Hz
+        match self.frame_sequencer_step {
+            0 | 4 => self.clock_lengths(),
+            2 | 6 => {
+                self.clock_lengths();
+                self.square1.clock_sweep();
+            }
+            7 => {
+                self.square1.clock_envelope();
+                self.square2.clock_envelope();
+                self.noise.clock_envelope();
+            }
+            _ => {}
+        }
+        self.frame_sequencer_step = (self.frame_sequencer_step + 1) % 8;
+    }
+
+    fn clock_lengths(&mut self) {
+        self.square1.clock_length();
+        self.square2.clock_length();
+        self.wave.clock_length();
+        self.noise.clock_length();
+    }
+
+    /// Returns the analog output of each channel, in [-1, 1], or 0 when its DAC is off.
+    pub fn get_channel_outputs(&self) -> [f32; CHANNEL_COUNT] {
+        let channels: [(bool, Byte); CHANNEL_COUNT] = [
+            (self.square1.is_dac_enabled(), self.square1.get_output()),
+            (self.square2.is_dac_enabled(), self.square2.get_output()),
+            (self.wave.is_dac_enabled(), self.wave.get_output()),
+            (self.noise.is_dac_enabled(), self.noise.get_output()),
+        ];
+
+        channels.map(|(dac_enabled, output)| {
+            if dac_enabled {
+                output as f32 / 7.5 - 1.0
+            } else {
+                0.0
+            }
+        })
+    }
+
+    fn mix(&self, outputs: &[f32; CHANNEL_COUNT]) -> (f32, f32) {
+        let mut left: f32 = 0.0;
+        let mut right: f32 = 0.0;
+
+        // NR51 routes channel n to the right output with bit n and to the left with bit n + 4
+        for (channel, output) in outputs.iter().enumerate() {
+            if !self.is_channel_audible(channel) {
+                continue;
+            }
+            if (self.nr51 & (0x10 << channel)) != 0 {
+                left += output;
+            }
+            if (self.nr51 & (0x01 << channel)) != 0 {
+                right += output;
+            }
+        }
+
+        let left_volume: f32 = (((self.nr50 >> 4) & 0x07) + 1) as f32 / 8.0;
+        let right_volume: f32 = ((self.nr50 & 0x07) + 1) as f32 / 8.0;
+        (
+            left * left_volume / CHANNEL_COUNT as f32,
+            right * right_volume / CHANNEL_COUNT as f32,
+        )
+    }
+
+    fn push_sample(&mut self) {
+        // Averaging every M-cycle since the last sample acts as a cheap low-pass filter
+        let count: f32 = self.sum_count.max(1) as f32;
+        let left: f32 = high_pass(
+            self.left_sum / count,
+            &mut self.left_capacitor,
+            self.high_pass_factor,
+        );
+        let right: f32 = high_pass(
+            self.right_sum / count,
+            &mut self.right_capacitor,
+            self.high_pass_factor,
+        );
+        self.left_sum = 0.0;
+        self.right_sum = 0.0;
+
+        // Keep at most a second of audio when nobody is draining the buffers
+        let limit: Size = self.sample_rate as Size;
+        if self.samples.len() >= limit * 2 {
+            // Drop whole frames so the left/right interleaving survives odd rates
+            self.samples.drain(..limit & !1);
+        }
+        self.samples.push(left);
+        self.samples.push(right);
+
+        for channel in 0..CHANNEL_COUNT {
+            let output: f32 = high_pass(
+                self.channel_sums[channel] / count,
+                &mut self.channel_capacitors[channel],
+                self.high_pass_factor,
+            );
+            self.channel_sums[channel] = 0.0;
+
+            let samples: &mut Vec<f32> = &mut self.channel_samples[channel];
+            if samples.len() >= limit {
+                samples.drain(..limit / 2);
+            }
+            samples.push(output);
+        }
+        self.sum_count = 0;
+    }
+}
+
+fn high_pass(input: f32, capacitor: &mut f32, factor: f32) -> f32 {
+    let output: f32 = input - *capacitor;
+    *capacitor = input - output * factor;
+    output
+}
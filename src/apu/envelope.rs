@@ -0,0 +1,73 @@
+use crate::common::{constant::DEFAULT_BYTE, types::Byte};
+
+pub const MAX_VOLUME: Byte = 15;
+
+pub struct Envelope {
+    register: Byte,
+    volume: Byte,
+    timer: Byte,
+}
+
+impl Envelope {
+    pub fn new() -> Self {
+        Envelope {
+            register: DEFAULT_BYTE,
+            volume: DEFAULT_BYTE,
+            timer: DEFAULT_BYTE,
+        }
+    }
+
+    pub fn read_register(&self) -> Byte {
+        self.register
+    }
+
+    pub fn write_register(&mut self, value: Byte) {
+        self.register = value;
+    }
+
+    /// The DAC is powered as long as the initial volume or the direction bit is set.
+    pub fn is_dac_enabled(&self) -> bool {
+        (self.register & 0xF8) != 0
+    }
+
+    pub fn get_initial_volume(&self) -> Byte {
+        self.register >> 4
+    }
+
+    pub fn is_increasing(&self) -> bool {
+        (self.register & 0x08) != 0
+    }
+
+    pub fn get_period(&self) -> Byte {
+        self.register & 0x07
+    }
+
+    pub fn get_volume(&self) -> Byte {
+        self.volume
+    }
+
+    pub fn trigger(&mut self) {
+        self.volume = self.get_initial_volume();
+        self.timer = self.get_period();
+    }
+
+    pub fn clock(&mut self) {
+        let period: Byte = self.get_period();
+        if period == 0 {
+            return;
+        }
+
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+
+        if self.timer == 0 {
+            self.timer = period;
+            if self.is_increasing() && self.volume < MAX_VOLUME {
+                self.volume += 1;
+            } else if !self.is_increasing() && self.volume > 0 {
+                self.volume -= 1;
+            }
+        }
+    }
+}
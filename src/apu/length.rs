@@ -0,0 +1,44 @@
+use crate::common::types::{Byte, Word};
+
+pub struct LengthCounter {
+    max: Word,
+    counter: Word,
+    enabled: bool,
+}
+
+impl LengthCounter {
+    pub fn new(max: Word) -> Self {
+        LengthCounter {
+            max,
+            counter: 0,
+            enabled: false,
+        }
+    }
+
+    pub fn load(&mut self, value: Byte) {
+        self.counter = self.max - value as Word;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn trigger(&mut self) {
+        if self.counter == 0 {
+            self.counter = self.max;
+        }
+    }
+
+    /// Returns true when the counter just expired and the channel must be silenced.
+    pub fn clock(&mut self) -> bool {
+        if self.enabled && self.counter > 0 {
+            self.counter -= 1;
+            return self.counter == 0;
+        }
+        false
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    apu::{envelope::Envelope, length::LengthCounter},
+    common::{
+        constant::{CPU_FREQUENCY, DEFAULT_BYTE},
+        types::{Byte, Cycles, Word},
+    },
+};
+
+const DIVISORS: [Cycles; 8] = [8, 16, 32, 48, 64, 80, 96, 112];
+const LFSR_SEED: Word = 0x7FFF;
+
+pub struct NoiseChannel {
+    enabled: bool,
+    polynomial: Byte,
+    timer: Cycles,
+    lfsr: Word,
+    length: LengthCounter,
+    envelope: Envelope,
+}
+
+impl NoiseChannel {
+    pub fn new() -> Self {
+        NoiseChannel {
+            enabled: false,
+            polynomial: DEFAULT_BYTE,
+            timer: 0,
+            lfsr: LFSR_SEED,
+            length: LengthCounter::new(64),
+            envelope: Envelope::new(),
+        }
+    }
+
+    pub fn read_register(&self, index: usize) -> Byte {
+        match index {
+            2 => self.envelope.read_register(),
+            3 => self.polynomial,
+            4 => ((self.length.is_enabled() as Byte) << 6) | 0xBF,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_register(&mut self, index: usize, value: Byte) {
+        match index {
+            1 => self.length.load(value & 0x3F),
+            2 => {
+                self.envelope.write_register(value);
+                if !self.envelope.is_dac_enabled() {
+                    self.enabled = false;
+                }
+            }
+            3 => self.polynomial = value,
+            4 => {
+                self.length.set_enabled((value & 0x40) != 0);
+                if (value & 0x80) != 0 {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.envelope.is_dac_enabled();
+        self.length.trigger();
+        self.timer = self.get_period();
+        self.envelope.trigger();
+        self.lfsr = LFSR_SEED;
+    }
+
+    fn get_clock_shift(&self) -> Byte {
+        self.polynomial >> 4
+    }
+
+    pub fn is_short_mode(&self) -> bool {
+        (self.polynomial & 0x08) != 0
+    }
+
+    fn get_period(&self) -> Cycles {
+        DIVISORS[(self.polynomial & 0x07) as usize] << self.get_clock_shift()
+    }
+
+    fn step_lfsr(&mut self) {
+        let feedback: Word = (self.lfsr & 0x01) ^ ((self.lfsr >> 1) & 0x01);
+        self.lfsr = (self.lfsr >> 1) | (feedback << 14);
+
+        // The 7-bit mode also feeds bit 6, giving a much shorter, more tonal sequence
+        if self.is_short_mode() {
+            self.lfsr = (self.lfsr & !(1 << 6)) | (feedback << 6);
+        }
+    }
+
+    pub fn tick(&mut self, cycles: Cycles) {
+        let mut remaining: Cycles = cycles;
+        while remaining > 0 {
+            let step: Cycles = remaining.min(self.timer.max(1));
+            self.timer = self.timer.saturating_sub(step);
+            remaining -= step;
+
+            if self.timer == 0 {
+                self.timer = self.get_period();
+                // Clock shifts 14 and 15 stop the LFSR
+                if self.get_clock_shift() < 14 {
+                    self.step_lfsr();
+                }
+            }
+        }
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length.clock() {
+            self.enabled = false;
+        }
+    }
+
+    pub fn clock_envelope(&mut self) {
+        self.envelope.clock();
+    }
+
+    pub fn get_output(&self) -> Byte {
+        if !self.enabled || (self.lfsr & 0x01) != 0 {
+            return 0;
+        }
+        self.envelope.get_volume()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_dac_enabled(&self) -> bool {
+        self.envelope.is_dac_enabled()
+    }
+
+    /// Returns how many times per second the LFSR is clocked.
+    pub fn get_frequency_hz(&self) -> f32 {
+        CPU_FREQUENCY as f32 / self.get_period() as f32
+    }
+
+    pub fn get_envelope(&self) -> &Envelope {
+        &self.envelope
+    }
+
+    pub fn get_volume(&self) -> Byte {
+        self.envelope.get_volume()
+    }
+}
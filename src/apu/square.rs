@@ -0,0 +1,228 @@
+use crate::{
+    apu::{envelope::Envelope, length::LengthCounter},
+    common::{
+        constant::{CPU_FREQUENCY, DEFAULT_BYTE, DEFAULT_WORD},
+        types::{Byte, Cycles, Word},
+    },
+};
+
+const DUTY_PATTERNS: [[Byte; 8]; 4] = [
+    [0, 0, 0, 0, 0, 0, 0, 1],
+    [1, 0, 0, 0, 0, 0, 0, 1],
+    [1, 0, 0, 0, 0, 1, 1, 1],
+    [0, 1, 1, 1, 1, 1, 1, 0],
+];
+const MAX_FREQUENCY: Word = 2047;
+
+pub struct SquareChannel {
+    has_sweep: bool,
+    enabled: bool,
+    duty: Byte,
+    duty_position: Byte,
+    frequency: Word,
+    timer: Cycles,
+    length: LengthCounter,
+    envelope: Envelope,
+    sweep: Byte,
+    sweep_enabled: bool,
+    sweep_timer: Byte,
+    shadow_frequency: Word,
+}
+
+impl SquareChannel {
+    pub fn new(has_sweep: bool) -> Self {
+        SquareChannel {
+            has_sweep,
+            enabled: false,
+            duty: DEFAULT_BYTE,
+            duty_position: DEFAULT_BYTE,
+            frequency: DEFAULT_WORD,
+            timer: 0,
+            length: LengthCounter::new(64),
+            envelope: Envelope::new(),
+            sweep: DEFAULT_BYTE,
+            sweep_enabled: false,
+            sweep_timer: DEFAULT_BYTE,
+            shadow_frequency: DEFAULT_WORD,
+        }
+    }
+
+    /// Reads NRx0–NRx4, `index` being the offset from the first register of the channel.
+    pub fn read_register(&self, index: usize) -> Byte {
+        match index {
+            0 if self.has_sweep => self.sweep | 0x80,
+            1 => (self.duty << 6) | 0x3F,
+            2 => self.envelope.read_register(),
+            4 => ((self.length.is_enabled() as Byte) << 6) | 0xBF,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_register(&mut self, index: usize, value: Byte) {
+        match index {
+            0 if self.has_sweep => self.sweep = value & 0x7F,
+            1 => {
+                self.duty = value >> 6;
+                self.length.load(value & 0x3F);
+            }
+            2 => {
+                self.envelope.write_register(value);
+                if !self.envelope.is_dac_enabled() {
+                    self.enabled = false;
+                }
+            }
+            3 => self.frequency = (self.frequency & 0x0700) | value as Word,
+            4 => {
+                self.frequency = (self.frequency & 0x00FF) | (((value & 0x07) as Word) << 8);
+                self.length.set_enabled((value & 0x40) != 0);
+                if (value & 0x80) != 0 {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.envelope.is_dac_enabled();
+        self.length.trigger();
+        self.timer = self.get_period();
+        self.envelope.trigger();
+
+        if self.has_sweep {
+            self.shadow_frequency = self.frequency;
+            self.sweep_timer = self.get_sweep_period();
+            self.sweep_enabled = self.get_sweep_pace() != 0 || self.get_sweep_shift() != 0;
+            if self.get_sweep_shift() != 0 {
+                self.calculate_sweep();
+            }
+        }
+    }
+
+    fn get_period(&self) -> Cycles {
+        (2048 - self.frequency as Cycles) * 4
+    }
+
+    fn get_sweep_pace(&self) -> Byte {
+        (self.sweep >> 4) & 0x07
+    }
+
+    fn get_sweep_shift(&self) -> Byte {
+        self.sweep & 0x07
+    }
+
+    fn is_sweep_decreasing(&self) -> bool {
+        (self.sweep & 0x08) != 0
+    }
+
+    fn get_sweep_period(&self) -> Byte {
+        // A pace of 0 still reloads the timer with 8
+        match self.get_sweep_pace() {
+            0 => 8,
+            pace => pace,
+        }
+    }
+
+    fn calculate_sweep(&mut self) -> Word {
+        let delta: Word = self.shadow_frequency >> self.get_sweep_shift();
+        let frequency: Word = if self.is_sweep_decreasing() {
+            self.shadow_frequency.wrapping_sub(delta)
+        } else {
+            self.shadow_frequency + delta
+        };
+
+        if frequency > MAX_FREQUENCY {
+            self.enabled = false;
+        }
+        frequency
+    }
+
+    pub fn tick(&mut self, cycles: Cycles) {
+        let mut remaining: Cycles = cycles;
+        while remaining > 0 {
+            let step: Cycles = remaining.min(self.timer.max(1));
+            self.timer = self.timer.saturating_sub(step);
+            remaining -= step;
+
+            if self.timer == 0 {
+                self.timer = self.get_period();
+                self.duty_position = (self.duty_position + 1) % 8;
+            }
+        }
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length.clock() {
+            self.enabled = false;
+        }
+    }
+
+    pub fn clock_envelope(&mut self) {
+        self.envelope.clock();
+    }
+
+    pub fn clock_sweep(&mut self) {
+        if !self.has_sweep {
+            return;
+        }
+
+        if self.sweep_timer > 0 {
+            self.sweep_timer -= 1;
+        }
+        if self.sweep_timer != 0 {
+            return;
+        }
+
+        self.sweep_timer = self.get_sweep_period();
+        if self.sweep_enabled && self.get_sweep_pace() != 0 {
+            let frequency: Word = self.calculate_sweep();
+            if frequency <= MAX_FREQUENCY && self.get_sweep_shift() != 0 {
+                self.frequency = frequency;
+                self.shadow_frequency = frequency;
+                // The new frequency is checked again for overflow without being applied
+                self.calculate_sweep();
+            }
+        }
+    }
+
+    /// Returns the digital output of the channel, from 0 to 15.
+    pub fn get_output(&self) -> Byte {
+        if !self.enabled {
+            return 0;
+        }
+        DUTY_PATTERNS[self.duty as usize][self.duty_position as usize] * self.envelope.get_volume()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_dac_enabled(&self) -> bool {
+        self.envelope.is_dac_enabled()
+    }
+
+    pub fn get_frequency(&self) -> Word {
+        self.frequency
+    }
+
+    /// Returns the pitch in Hz, a full duty cycle lasting eight timer periods.
+    pub fn get_frequency_hz(&self) -> f32 {
+        CPU_FREQUENCY as f32 / (self.get_period() * 8) as f32
+    }
+
+    pub fn get_sweep(&self) -> Byte {
+        self.sweep
+    }
+
+    pub fn get_envelope(&self) -> &Envelope {
+        &self.envelope
+    }
+
+    pub fn get_duty(&self) -> Byte {
+        self.duty
+    }
+
+    pub fn get_volume(&self) -> Byte {
+        self.envelope.get_volume()
+    }
+}
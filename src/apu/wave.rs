@@ -0,0 +1,164 @@
+use crate::{
+    apu::length::LengthCounter,
+    common::{
+        address::WAVE_RAM,
+        constant::{CPU_FREQUENCY, DEFAULT_BYTE, DEFAULT_WORD},
+        types::{Byte, Cycles, Word},
+    },
+};
+
+const WAVE_SAMPLES: Byte = 32;
+
+pub struct WaveChannel {
+    enabled: bool,
+    dac_enabled: bool,
+    volume_code: Byte,
+    frequency: Word,
+    timer: Cycles,
+    position: Byte,
+    sample: Byte,
+    length: LengthCounter,
+    wave_ram: [Byte; WAVE_RAM.size],
+}
+
+impl WaveChannel {
+    pub fn new() -> Self {
+        WaveChannel {
+            enabled: false,
+            dac_enabled: false,
+            volume_code: DEFAULT_BYTE,
+            frequency: DEFAULT_WORD,
+            timer: 0,
+            position: DEFAULT_BYTE,
+            sample: DEFAULT_BYTE,
+            length: LengthCounter::new(256),
+            wave_ram: [DEFAULT_BYTE; WAVE_RAM.size],
+        }
+    }
+
+    /// Builds a powered-off channel that keeps the wave RAM, which survives NR52 resets.
+    pub fn with_wave_ram(wave_ram: [Byte; WAVE_RAM.size]) -> Self {
+        WaveChannel {
+            wave_ram,
+            ..WaveChannel::new()
+        }
+    }
+
+    pub fn read_register(&self, index: usize) -> Byte {
+        match index {
+            0 => ((self.dac_enabled as Byte) << 7) | 0x7F,
+            2 => (self.volume_code << 5) | 0x9F,
+            4 => ((self.length.is_enabled() as Byte) << 6) | 0xBF,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_register(&mut self, index: usize, value: Byte) {
+        match index {
+            0 => {
+                self.dac_enabled = (value & 0x80) != 0;
+                if !self.dac_enabled {
+                    self.enabled = false;
+                }
+            }
+            1 => self.length.load(value),
+            2 => self.volume_code = (value >> 5) & 0x03,
+            3 => self.frequency = (self.frequency & 0x0700) | value as Word,
+            4 => {
+                self.frequency = (self.frequency & 0x00FF) | (((value & 0x07) as Word) << 8);
+                self.length.set_enabled((value & 0x40) != 0);
+                if (value & 0x80) != 0 {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn get_wave_ram(&self) -> &[Byte; WAVE_RAM.size] {
+        &self.wave_ram
+    }
+
+    pub fn read_wave_ram(&self, index: usize) -> Byte {
+        self.wave_ram[index]
+    }
+
+    pub fn write_wave_ram(&mut self, index: usize, value: Byte) {
+        self.wave_ram[index] = value;
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.dac_enabled;
+        self.length.trigger();
+        self.timer = self.get_period();
+        self.position = 0;
+    }
+
+    fn get_period(&self) -> Cycles {
+        (2048 - self.frequency as Cycles) * 2
+    }
+
+    fn read_sample(&self, position: Byte) -> Byte {
+        // Each byte holds two 4-bit samples, the upper nibble being played first
+        let byte: Byte = self.wave_ram[(position / 2) as usize];
+        if position % 2 == 0 {
+            byte >> 4
+        } else {
+            byte & 0x0F
+        }
+    }
+
+    pub fn tick(&mut self, cycles: Cycles) {
+        let mut remaining: Cycles = cycles;
+        while remaining > 0 {
+            let step: Cycles = remaining.min(self.timer.max(1));
+            self.timer = self.timer.saturating_sub(step);
+            remaining -= step;
+
+            if self.timer == 0 {
+                self.timer = self.get_period();
+                self.position = (self.position + 1) % WAVE_SAMPLES;
+                self.sample = self.read_sample(self.position);
+            }
+        }
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length.clock() {
+            self.enabled = false;
+        }
+    }
+
+    pub fn get_output(&self) -> Byte {
+        if !self.enabled {
+            return 0;
+        }
+
+        match self.volume_code {
+            0 => 0,
+            1 => self.sample,
+            2 => self.sample >> 1,
+            _ => self.sample >> 2,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn is_dac_enabled(&self) -> bool {
+        self.dac_enabled
+    }
+
+    pub fn get_frequency(&self) -> Word {
+        self.frequency
+    }
+
+    pub fn get_frequency_hz(&self) -> f32 {
+        CPU_FREQUENCY as f32 / (self.get_period() * WAVE_SAMPLES as Cycles) as f32
+    }
+
+    pub fn get_volume_code(&self) -> Byte {
+        self.volume_code
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    audio::{ring_buffer::RingBuffer, sink::AudioSink},
+    common::{constant::AUDIO_BUFFER_MS, types::Size},
+};
+use cpal::{
+    Device, FromSample, OutputCallbackInfo, SampleFormat, SizedSample, Stream, StreamConfig,
+    SupportedStreamConfig,
+    traits::{DeviceTrait, HostTrait, StreamTrait},
+};
+use std::sync::{Arc, Mutex};
+
+/// Plays samples on the default sound device through a ring buffer drained by the audio thread.
+pub struct DeviceSink {
+    _stream: Stream,
+    buffer: Arc<Mutex<RingBuffer>>,
+    sample_rate: u32,
+}
+
+impl DeviceSink {
+    pub fn open() -> Result<DeviceSink, String> {
+        let device: Device = match cpal::default_host().default_output_device() {
+            Some(device) => device,
+            None => return Err("aucun périphérique de sortie".to_string()),
+        };
+        let supported: SupportedStreamConfig = device
+            .default_output_config()
+            .map_err(|error| error.to_string())?;
+        let config: StreamConfig = supported.config();
+        let sample_rate: u32 = config.sample_rate.0;
+
+        let capacity: Size = sample_rate as Size * 2 * AUDIO_BUFFER_MS as Size / 1000;
+        let buffer: Arc<Mutex<RingBuffer>> = Arc::new(Mutex::new(RingBuffer::new(capacity)));
+
+        let stream: Stream = match supported.sample_format() {
+            SampleFormat::F32 => DeviceSink::build_stream::<f32>(&device, &config, &buffer)?,
+            SampleFormat::I16 => DeviceSink::build_stream::<i16>(&device, &config, &buffer)?,
+            SampleFormat::U16 => DeviceSink::build_stream::<u16>(&device, &config, &buffer)?,
+            other => {
+                return Err(format!(
+                    "format d'échantillon non pris en charge : {}",
+                    other
+                ));
+            }
+        };
+        stream.play().map_err(|error| error.to_string())?;
+
+        Ok(DeviceSink {
+            _stream: stream,
+            buffer,
+            sample_rate,
+        })
+    }
+
+    fn build_stream<T: SizedSample + FromSample<f32>>(
+        device: &Device,
+        config: &StreamConfig,
+        buffer: &Arc<Mutex<RingBuffer>>,
+    ) -> Result<Stream, String> {
+        let buffer: Arc<Mutex<RingBuffer>> = Arc::clone(buffer);
+        let channels: Size = config.channels as Size;
+        let mut frames: Vec<f32> = Vec::new();
+
+        let data_callback = move |data: &mut [T], _: &OutputCallbackInfo| {
+            frames.resize(data.len() / channels * 2, 0.0);
+            let read: Size = match buffer.lock() {
+                Ok(mut buffer) => buffer.pop(&mut frames),
+                Err(_) => 0,
+            };
+            // Play silence on underrun rather than repeating stale samples
+            frames[read..].fill(0.0);
+
+            for (index, frame) in data.chunks_mut(channels).enumerate() {
+                let left: f32 = frames[index * 2];
+                let right: f32 = frames[index * 2 + 1];
+                for (channel, sample) in frame.iter_mut().enumerate() {
+                    let value: f32 = match (channels, channel) {
+                        (1, _) => (left + right) / 2.0,
+                        (_, 0) => left,
+                        (_, 1) => right,
+                        _ => 0.0,
+                    };
+                    *sample = T::from_sample(value);
+                }
+            }
+        };
+        let error_callback = |error| eprintln!("Erreur du flux audio : {}", error);
+
+        device
+            .build_output_stream(config, data_callback, error_callback, None)
+            .map_err(|error| error.to_string())
+    }
+}
+
+impl AudioSink for DeviceSink {
+    fn get_sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn write(&mut self, samples: &[f32]) {
+        if let Ok(mut buffer) = self.buffer.lock() {
+            buffer.push(samples);
+        }
+    }
+
+    fn get_fill_level(&self) -> f32 {
+        match self.buffer.lock() {
+            Ok(buffer) => buffer.get_fill_level(),
+            Err(_) => 0.0,
+        }
+    }
+}
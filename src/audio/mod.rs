@@ -0,0 +1,8 @@
+pub mod device;
+pub mod output;
+pub mod recorder;
+pub mod resampler;
+pub mod ring_buffer;
+pub mod sink;
+pub mod vgm;
+pub mod wav;
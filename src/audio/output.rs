@@ -0,0 +1,74 @@
+use crate::{
+    audio::{
+        device::DeviceSink,
+        resampler::Resampler,
+        sink::{AudioSink, NullSink, TARGET_FILL_LEVEL, WavSink},
+    },
+    common::constant::{DEFAULT_SAMPLE_RATE, MAX_RATE_DELTA},
+};
+use std::{env, path::Path};
+
+/// Environment variable selecting the audio sink: `null`, a `.wav` path, or the sound device.
+pub const AUDIO_SINK_VARIABLE: &str = "GBRS_AUDIO";
+
+pub struct AudioOutput {
+    sink: Box<dyn AudioSink>,
+    resampler: Resampler,
+    buffer: Vec<f32>,
+}
+
+impl AudioOutput {
+    pub fn new(sink: Box<dyn AudioSink>) -> Self {
+        AudioOutput {
+            sink,
+            resampler: Resampler::new(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Opens the sink chosen by `GBRS_AUDIO`, falling back to silence when no device is usable.
+    pub fn open() -> Self {
+        let sink: Box<dyn AudioSink> = match env::var(AUDIO_SINK_VARIABLE) {
+            Ok(value) if value == "null" => Box::new(NullSink::new(DEFAULT_SAMPLE_RATE)),
+            Ok(value) if value.ends_with(".wav") => {
+                match WavSink::create(Path::new(&value), DEFAULT_SAMPLE_RATE) {
+                    Ok(sink) => Box::new(sink),
+                    Err(error) => {
+                        eprintln!("Impossible de créer le fichier audio {} : {}", value, error);
+                        Box::new(NullSink::new(DEFAULT_SAMPLE_RATE))
+                    }
+                }
+            }
+            _ => match DeviceSink::open() {
+                Ok(sink) => Box::new(sink),
+                Err(error) => {
+                    eprintln!("Son désactivé, aucune sortie audio disponible : {}", error);
+                    Box::new(NullSink::new(DEFAULT_SAMPLE_RATE))
+                }
+            },
+        };
+        AudioOutput::new(sink)
+    }
+
+    pub fn get_sample_rate(&self) -> u32 {
+        self.sink.get_sample_rate()
+    }
+
+    /// Returns how many output frames to produce per input frame to keep the buffer half full.
+    fn get_rate_ratio(&self) -> f64 {
+        let fill_level: f64 = self.sink.get_fill_level() as f64;
+        let error: f64 = (TARGET_FILL_LEVEL as f64 - fill_level) / TARGET_FILL_LEVEL as f64;
+        1.0 + MAX_RATE_DELTA * error.clamp(-1.0, 1.0)
+    }
+
+    pub fn push(&mut self, samples: &[f32]) {
+        let ratio: f64 = self.get_rate_ratio();
+        self.resampler.process(samples, ratio, &mut self.buffer);
+        self.sink.write(&self.buffer);
+        self.buffer.clear();
+    }
+
+    pub fn finish(&mut self) {
+        self.sink.finish();
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{apu::apu::CHANNEL_COUNT, audio::wav::WavWriter};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Records the stereo mix to a WAV file and optionally each channel to its own mono file.
+pub struct AudioRecorder {
+    path: PathBuf,
+    mix: WavWriter,
+    channels: Vec<WavWriter>,
+}
+
+impl AudioRecorder {
+    pub fn create(path: &Path, sample_rate: u32, per_channel: bool) -> io::Result<AudioRecorder> {
+        let mut channels: Vec<WavWriter> = Vec::new();
+        if per_channel {
+            for channel in 0..CHANNEL_COUNT {
+                let channel_path: PathBuf = AudioRecorder::get_channel_path(path, channel);
+                channels.push(WavWriter::create(&channel_path, sample_rate, 1)?);
+            }
+        }
+
+        Ok(AudioRecorder {
+            path: path.to_path_buf(),
+            mix: WavWriter::create(path, sample_rate, 2)?,
+            channels,
+        })
+    }
+
+    /// Builds `name.ch1.wav` to `name.ch4.wav` next to the stereo recording.
+    pub fn get_channel_path(path: &Path, channel: usize) -> PathBuf {
+        path.with_extension(format!("ch{}.wav", channel + 1))
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write(&mut self, mix: &[f32], channels: &[Vec<f32>; CHANNEL_COUNT]) -> io::Result<()> {
+        self.mix.write_samples(mix)?;
+        for (writer, samples) in self.channels.iter_mut().zip(channels) {
+            writer.write_samples(samples)?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.mix.finish()?;
+        for writer in &mut self.channels {
+            writer.finish()?;
+        }
+        Ok(())
+    }
+}
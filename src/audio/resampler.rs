@@ -0,0 +1,37 @@
+use crate::common::types::Size;
+
+const CHANNELS: Size = 2;
+
+/// Linear interpolation resampler for interleaved stereo samples.
+pub struct Resampler {
+    position: f64,
+    previous: [f32; CHANNELS],
+}
+
+impl Resampler {
+    pub fn new() -> Self {
+        Resampler {
+            position: 0.0,
+            previous: [0.0; CHANNELS],
+        }
+    }
+
+    /// Appends the resampled frames to `output`, producing `ratio` output frames per input frame.
+    pub fn process(&mut self, input: &[f32], ratio: f64, output: &mut Vec<f32>) {
+        let step: f64 = 1.0 / ratio;
+
+        for frame in input.chunks_exact(CHANNELS) {
+            while self.position < 1.0 {
+                let weight: f32 = self.position as f32;
+                for channel in 0..CHANNELS {
+                    let previous: f32 = self.previous[channel];
+                    output.push(previous + (frame[channel] - previous) * weight);
+                }
+                self.position += step;
+            }
+
+            self.position -= 1.0;
+            self.previous.copy_from_slice(frame);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::common::types::Size;
+
+/// Fixed-size FIFO of interleaved samples shared between the emulator and the audio callback.
+pub struct RingBuffer {
+    samples: Vec<f32>,
+    read_index: Size,
+    length: Size,
+}
+
+impl RingBuffer {
+    pub fn new(capacity: Size) -> Self {
+        RingBuffer {
+            samples: vec![0.0; capacity.max(1)],
+            read_index: 0,
+            length: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> Size {
+        self.samples.len()
+    }
+
+    pub fn get_fill_level(&self) -> f32 {
+        self.length as f32 / self.capacity() as f32
+    }
+
+    /// Appends as many samples as fit and returns how many were written; the rest is dropped.
+    pub fn push(&mut self, samples: &[f32]) -> Size {
+        let count: Size = samples.len().min(self.capacity() - self.length);
+        for sample in &samples[..count] {
+            let write_index: Size = (self.read_index + self.length) % self.capacity();
+            self.samples[write_index] = *sample;
+            self.length += 1;
+        }
+        count
+    }
+
+    /// Fills `output` from the oldest samples and returns how many were read.
+    pub fn pop(&mut self, output: &mut [f32]) -> Size {
+        let count: Size = output.len().min(self.length);
+        for sample in &mut output[..count] {
+            *sample = self.samples[self.read_index];
+            self.read_index = (self.read_index + 1) % self.capacity();
+            self.length -= 1;
+        }
+        count
+    }
+}
@@ -0,0 +1,70 @@
+use crate::audio::wav::WavWriter;
+use std::{io, path::Path};
+
+/// Fill level the dynamic rate control aims for in buffered sinks.
+pub const TARGET_FILL_LEVEL: f32 = 0.5;
+
+pub trait AudioSink {
+    fn get_sample_rate(&self) -> u32;
+    fn write(&mut self, samples: &[f32]);
+
+    /// Returns how full the output buffer is, from 0 to 1. Unbuffered sinks report the target.
+    fn get_fill_level(&self) -> f32 {
+        TARGET_FILL_LEVEL
+    }
+
+    /// Flushes anything the sink still holds; called once when the emulator shuts down.
+    fn finish(&mut self) {}
+}
+
+/// Discards every sample, for machines without a sound device.
+pub struct NullSink {
+    sample_rate: u32,
+}
+
+impl NullSink {
+    pub fn new(sample_rate: u32) -> Self {
+        NullSink { sample_rate }
+    }
+}
+
+impl AudioSink for NullSink {
+    fn get_sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn write(&mut self, _samples: &[f32]) {}
+}
+
+/// Writes the stereo output to a WAV file instead of playing it.
+pub struct WavSink {
+    writer: WavWriter,
+    sample_rate: u32,
+}
+
+impl WavSink {
+    pub fn create(path: &Path, sample_rate: u32) -> io::Result<WavSink> {
+        Ok(WavSink {
+            writer: WavWriter::create(path, sample_rate, 2)?,
+            sample_rate,
+        })
+    }
+}
+
+impl AudioSink for WavSink {
+    fn get_sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn write(&mut self, samples: &[f32]) {
+        if let Err(error) = self.writer.write_samples(samples) {
+            eprintln!("Impossible d'écrire le fichier audio : {}", error);
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Err(error) = self.writer.finish() {
+            eprintln!("Impossible de terminer le fichier audio : {}", error);
+        }
+    }
+}
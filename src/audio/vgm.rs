@@ -0,0 +1,126 @@
+use crate::common::{
+    address::AUDIO,
+    constant::CPU_FREQUENCY,
+    types::{Address, Byte, Cycles, Size},
+};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+const VGM_VERSION: u32 = 0x0000_0161;
+const VGM_SAMPLE_RATE: u64 = 44_100;
+const HEADER_SIZE: Size = 0x100;
+const DATA_OFFSET_FIELD: Size = 0x34;
+const DMG_CLOCK_FIELD: Size = 0x80;
+
+const COMMAND_DMG_WRITE: Byte = 0xB3;
+const COMMAND_WAIT: Byte = 0x61;
+const COMMAND_WAIT_NTSC_FRAME: Byte = 0x62;
+const COMMAND_WAIT_PAL_FRAME: Byte = 0x63;
+const COMMAND_WAIT_SHORT: Byte = 0x70;
+const COMMAND_END: Byte = 0x66;
+
+/// Logs APU register writes to a VGM 1.61 file using the native Game Boy DMG chip.
+pub struct VgmLogger {
+    path: PathBuf,
+    file: BufWriter<File>,
+    data_size: u32,
+    elapsed_cycles: u64,
+    written_samples: u64,
+    finished: bool,
+}
+
+impl VgmLogger {
+    pub fn create(path: &Path) -> io::Result<VgmLogger> {
+        let mut logger: VgmLogger = VgmLogger {
+            path: path.to_path_buf(),
+            file: BufWriter::new(File::create(path)?),
+            data_size: 0,
+            elapsed_cycles: 0,
+            written_samples: 0,
+            finished: false,
+        };
+        logger.write_header()?;
+        Ok(logger)
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        let mut header: [Byte; HEADER_SIZE] = [0; HEADER_SIZE];
+        let eof_offset: u32 = (HEADER_SIZE as u32 + self.data_size) - 4;
+
+        header[0x00..0x04].copy_from_slice(b"Vgm ");
+        header[0x04..0x08].copy_from_slice(&eof_offset.to_le_bytes());
+        header[0x08..0x0C].copy_from_slice(&VGM_VERSION.to_le_bytes());
+        header[0x18..0x1C].copy_from_slice(&(self.written_samples as u32).to_le_bytes());
+        // The data offset is relative to its own field
+        let data_offset: u32 = (HEADER_SIZE - DATA_OFFSET_FIELD) as u32;
+        header[DATA_OFFSET_FIELD..DATA_OFFSET_FIELD + 4]
+            .copy_from_slice(&data_offset.to_le_bytes());
+        header[DMG_CLOCK_FIELD..DMG_CLOCK_FIELD + 4].copy_from_slice(&CPU_FREQUENCY.to_le_bytes());
+
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(&header)
+    }
+
+    fn write_command(&mut self, bytes: &[Byte]) -> io::Result<()> {
+        self.file.write_all(bytes)?;
+        self.data_size += bytes.len() as u32;
+        Ok(())
+    }
+
+    pub fn advance(&mut self, cycles: Cycles) {
+        self.elapsed_cycles += cycles as u64;
+    }
+
+    /// Emits wait commands up to the current time, rounded to the 44.1 kHz VGM clock.
+    fn flush_wait(&mut self) -> io::Result<()> {
+        let target: u64 = self.elapsed_cycles * VGM_SAMPLE_RATE / CPU_FREQUENCY as u64;
+        let mut remaining: u64 = target - self.written_samples;
+        self.written_samples = target;
+
+        while remaining > 0 {
+            let samples: u64 = remaining.min(u16::MAX as u64);
+            match samples {
+                735 => self.write_command(&[COMMAND_WAIT_NTSC_FRAME])?,
+                882 => self.write_command(&[COMMAND_WAIT_PAL_FRAME])?,
+                1..=16 => self.write_command(&[COMMAND_WAIT_SHORT + (samples - 1) as Byte])?,
+                _ => {
+                    let bytes: [Byte; 2] = (samples as u16).to_le_bytes();
+                    self.write_command(&[COMMAND_WAIT, bytes[0], bytes[1]])?;
+                }
+            }
+            remaining -= samples;
+        }
+        Ok(())
+    }
+
+    pub fn write_register(&mut self, address: Address, value: Byte) -> io::Result<()> {
+        self.flush_wait()?;
+        let register: Byte = (address - AUDIO.start) as Byte;
+        self.write_command(&[COMMAND_DMG_WRITE, register, value])
+    }
+
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+
+        self.flush_wait()?;
+        self.write_command(&[COMMAND_END])?;
+        self.write_header()?;
+        self.file.flush()
+    }
+}
+
+impl Drop for VgmLogger {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
@@ -0,0 +1,76 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+const HEADER_SIZE: u32 = 44;
+const BITS_PER_SAMPLE: u16 = 16;
+
+/// Writes 16-bit PCM WAV files, patching the chunk sizes in the header when finished.
+pub struct WavWriter {
+    file: BufWriter<File>,
+    data_size: u32,
+    finished: bool,
+}
+
+impl WavWriter {
+    pub fn create(path: &Path, sample_rate: u32, channels: u16) -> io::Result<WavWriter> {
+        let mut writer: WavWriter = WavWriter {
+            file: BufWriter::new(File::create(path)?),
+            data_size: 0,
+            finished: false,
+        };
+        writer.write_header(sample_rate, channels)?;
+        Ok(writer)
+    }
+
+    fn write_header(&mut self, sample_rate: u32, channels: u16) -> io::Result<()> {
+        let block_align: u16 = channels * BITS_PER_SAMPLE / 8;
+        let byte_rate: u32 = sample_rate * block_align as u32;
+
+        self.file.write_all(b"RIFF")?;
+        self.file.write_all(&(HEADER_SIZE - 8).to_le_bytes())?;
+        self.file.write_all(b"WAVE")?;
+        self.file.write_all(b"fmt ")?;
+        self.file.write_all(&16u32.to_le_bytes())?;
+        self.file.write_all(&1u16.to_le_bytes())?;
+        self.file.write_all(&channels.to_le_bytes())?;
+        self.file.write_all(&sample_rate.to_le_bytes())?;
+        self.file.write_all(&byte_rate.to_le_bytes())?;
+        self.file.write_all(&block_align.to_le_bytes())?;
+        self.file.write_all(&BITS_PER_SAMPLE.to_le_bytes())?;
+        self.file.write_all(b"data")?;
+        self.file.write_all(&0u32.to_le_bytes())
+    }
+
+    /// Converts samples in [-1, 1] to signed 16-bit integers.
+    pub fn write_samples(&mut self, samples: &[f32]) -> io::Result<()> {
+        for sample in samples {
+            let value: i16 = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+            self.file.write_all(&value.to_le_bytes())?;
+        }
+        self.data_size += (samples.len() * size_of::<i16>()) as u32;
+        Ok(())
+    }
+
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+
+        self.file.seek(SeekFrom::Start(4))?;
+        self.file
+            .write_all(&(HEADER_SIZE - 8 + self.data_size).to_le_bytes())?;
+        self.file.seek(SeekFrom::Start(HEADER_SIZE as u64 - 4))?;
+        self.file.write_all(&self.data_size.to_le_bytes())?;
+        self.file.flush()
+    }
+}
+
+impl Drop for WavWriter {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
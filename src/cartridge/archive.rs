@@ -0,0 +1,96 @@
+use crate::{cartridge::error::CartridgeError, common::types::Byte};
+use flate2::read::GzDecoder;
+use sevenz_rust::{Password, SevenZReader};
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::Path,
+};
+use zip::ZipArchive;
+
+pub const ROM_EXTENSIONS: [&str; 2] = ["gb", "gbc"];
+pub const ARCHIVE_EXTENSIONS: [&str; 3] = ["zip", "gz", "7z"];
+
+/// Reads the ROM bytes from a plain `.gb`/`.gbc` file or from the first Game Boy ROM
+/// found inside a `.zip`, `.gz` or `.7z` archive.
+pub fn read_rom_file(path: &Path) -> Result<Vec<Byte>, CartridgeError> {
+    let result: Result<Option<Vec<Byte>>, String> = match get_extension(path).as_str() {
+        "zip" => read_zip(path),
+        "gz" => read_gzip(path),
+        "7z" => read_7z(path),
+        _ => fs::read(path).map(Some).map_err(|error| error.to_string()),
+    };
+
+    match result {
+        Ok(Some(bytes)) => Ok(bytes),
+        Ok(None) => Err(CartridgeError::NoRomInArchive {
+            path: path.display().to_string(),
+        }),
+        Err(reason) => Err(CartridgeError::Unreadable {
+            path: path.display().to_string(),
+            reason,
+        }),
+    }
+}
+
+pub fn get_extension(path: &Path) -> String {
+    match path.extension() {
+        Some(extension) => extension.to_string_lossy().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+fn is_rom_name(name: &str) -> bool {
+    ROM_EXTENSIONS.contains(&get_extension(Path::new(name)).as_str())
+}
+
+fn read_zip(path: &Path) -> Result<Option<Vec<Byte>>, String> {
+    let file: File = File::open(path).map_err(|error| error.to_string())?;
+    let mut archive: ZipArchive<File> = ZipArchive::new(file).map_err(|error| error.to_string())?;
+
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|error| error.to_string())?;
+        if entry.is_file() && is_rom_name(entry.name()) {
+            let mut bytes: Vec<Byte> = Vec::new();
+            entry
+                .read_to_end(&mut bytes)
+                .map_err(|error| error.to_string())?;
+            return Ok(Some(bytes));
+        }
+    }
+
+    Ok(None)
+}
+
+fn read_gzip(path: &Path) -> Result<Option<Vec<Byte>>, String> {
+    // A gzip stream holds a single file, usually named `game.gb.gz`
+    let file: File = File::open(path).map_err(|error| error.to_string())?;
+    let mut bytes: Vec<Byte> = Vec::new();
+    GzDecoder::new(file)
+        .read_to_end(&mut bytes)
+        .map_err(|error| error.to_string())?;
+    Ok(Some(bytes))
+}
+
+fn read_7z(path: &Path) -> Result<Option<Vec<Byte>>, String> {
+    let mut archive: SevenZReader<File> =
+        SevenZReader::open(path, Password::empty()).map_err(|error| error.to_string())?;
+
+    let mut rom: Option<Vec<Byte>> = None;
+    archive
+        .for_each_entries(|entry, reader| {
+            if rom.is_some() {
+                return Ok(false);
+            }
+            if !entry.is_directory() && is_rom_name(entry.name()) {
+                let mut bytes: Vec<Byte> = Vec::new();
+                reader.read_to_end(&mut bytes)?;
+                rom = Some(bytes);
+                return Ok(false);
+            }
+            Ok(true)
+        })
+        .map_err(|error| error.to_string())?;
+
+    Ok(rom)
+}
@@ -0,0 +1,57 @@
+use crate::common::types::{Byte, Size};
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+pub enum CartridgeError {
+    Unreadable { path: String, reason: String },
+    TooShort { size: Size },
+    BadLogo,
+    BadHeaderChecksum { expected: Byte, computed: Byte },
+    UnsupportedMapper { code: Byte, name: String },
+    SizeMismatch { header: Size, file: Size },
+    UnknownRomSize { code: Byte },
+    NoRomInArchive { path: String },
+    InvalidPatch { path: String, reason: String },
+}
+
+impl fmt::Display for CartridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CartridgeError::Unreadable { path, reason } => {
+                write!(f, "Impossible de lire le fichier {} : {}", path, reason)
+            }
+            CartridgeError::TooShort { size } => write!(
+                f,
+                "Fichier trop court ({} octets) pour contenir un en-tête Game Boy",
+                size
+            ),
+            CartridgeError::BadLogo => write!(f, "Le logo Nintendo de l'en-tête est invalide"),
+            CartridgeError::BadHeaderChecksum { expected, computed } => write!(
+                f,
+                "Somme de contrôle de l'en-tête invalide (attendue 0x{:02X}, calculée 0x{:02X})",
+                expected, computed
+            ),
+            CartridgeError::UnsupportedMapper { code, name } => write!(
+                f,
+                "Type de cartouche non pris en charge : {} (0x{:02X})",
+                name, code
+            ),
+            CartridgeError::SizeMismatch { header, file } => write!(
+                f,
+                "Taille de ROM incohérente : l'en-tête annonce {} octets, le fichier en fait {}",
+                header, file
+            ),
+            CartridgeError::UnknownRomSize { code } => {
+                write!(f, "Taille de ROM inconnue dans l'en-tête (0x{:02X})", code)
+            }
+            CartridgeError::NoRomInArchive { path } => {
+                write!(f, "Aucune ROM Game Boy trouvée dans l'archive {}", path)
+            }
+            CartridgeError::InvalidPatch { path, reason } => {
+                write!(f, "Impossible d'appliquer le patch {} : {}", path, reason)
+            }
+        }
+    }
+}
+
+impl Error for CartridgeError {}
@@ -0,0 +1,581 @@
+use crate::common::{
+    address::{
+        CARTRIDGE_TYPE, CGB_FLAG, DESTINATION_CODE, ENTRY_POINT, GLOBAL_CHECKSUM, HEADER_CHECKSUM,
+        MANUFACTURER_CODE, MASK_ROM_VERSION, NEW_LICENSEE_CODE, OLD_LICENSEE_CODE, RAM_SIZE,
+        ROM_SIZE, SGB_FLAG, TITLE,
+    },
+    constant::{DEFAULT_BYTE, DEFAULT_WORD},
+    types::{Address, Byte, Size, Word},
+};
+
+pub struct CartridgeHeader {
+    pub entry_point_values: [Byte; ENTRY_POINT.size],
+    pub title: String,
+    pub manufacturer_code: Option<String>,
+    pub cgb_support: CGBSupport,
+    pub licensee_code: String,
+    pub new_licensee_code: Option<String>,
+    pub supports_sgb: bool,
+    pub cartridge_type: CartridgeType,
+    pub rom_size: Size,
+    pub rom_number_banks: usize,
+    pub ram_size: Size,
+    pub destination_code: DestinationCode,
+    pub mask_rom_version_number: Byte,
+    pub header_checksum: Byte,
+    pub is_header_checksum_valid: bool,
+    pub global_checksum: Word,
+    pub is_global_checksum_valid: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CGBSupport {
+    None,
+    Enhanced,
+    Only,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CartridgeType {
+    ROM_Only,
+    MBC1,
+    MBC1_RAM,
+    MBC1_RAM_Battery,
+    MBC2,
+    MBC2_Battery,
+    ROM_RAM,
+    ROM_RAM_Battery,
+    MMM01,
+    MMM01_RAM,
+    MMM01_RAM_Battery,
+    MBC3_Timer_Battery,
+    MBC3_Timer_RAM_Battery,
+    MBC3,
+    MBC3_RAM,
+    MBC3_RAM_Battery,
+    MBC5,
+    MBC5_RAM,
+    MBC5_RAM_Battery,
+    MBC5_Rumble,
+    MBC5_Rumble_RAM,
+    MBC5_Rumble_RAM_Battery,
+    Pocket_Camera,
+    Bandai_TAMA5,
+    HuC3,
+    HuC1_RAM_Battery,
+    Unknown(Byte),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DestinationCode {
+    Japan,
+    Overseas,
+    Unknown(Byte),
+}
+
+impl CartridgeType {
+    pub fn has_battery(&self) -> bool {
+        matches!(
+            self,
+            CartridgeType::MBC1_RAM_Battery
+                | CartridgeType::MBC2_Battery
+                | CartridgeType::ROM_RAM_Battery
+                | CartridgeType::MMM01_RAM_Battery
+                | CartridgeType::MBC3_Timer_Battery
+                | CartridgeType::MBC3_Timer_RAM_Battery
+                | CartridgeType::MBC3_RAM_Battery
+                | CartridgeType::MBC5_RAM_Battery
+                | CartridgeType::MBC5_Rumble_RAM_Battery
+                | CartridgeType::HuC1_RAM_Battery
+        )
+    }
+}
+
+impl CartridgeHeader {
+    pub fn parse(bytes: &[Byte]) -> CartridgeHeader {
+        let cgb_support: CGBSupport = CartridgeHeader::extract_cgb_support(bytes);
+        let manufacturer_code: Option<String> =
+            CartridgeHeader::extract_manufacturer_code(bytes, cgb_support);
+        let title: String =
+            CartridgeHeader::extract_title(bytes, cgb_support, manufacturer_code.is_some());
+        // Unknown size codes are rejected before parsing, see Cartridge::check_header
+        let (rom_size, rom_number_banks): (Size, usize) =
+            CartridgeHeader::decode_rom_size(bytes[ROM_SIZE.offset]).unwrap_or((0, 0));
+        let header_checksum: Byte = bytes[HEADER_CHECKSUM.offset];
+        let global_checksum: Word =
+            ((bytes[GLOBAL_CHECKSUM.start] as Word) << 8) | bytes[GLOBAL_CHECKSUM.end] as Word;
+
+        CartridgeHeader {
+            entry_point_values: CartridgeHeader::extract_entry_point_values(bytes),
+            title,
+            manufacturer_code,
+            cgb_support,
+            licensee_code: CartridgeHeader::extract_licensee_code(bytes),
+            new_licensee_code: CartridgeHeader::extract_new_licensee_code(bytes),
+            supports_sgb: CartridgeHeader::extract_sgb_flag(bytes),
+            cartridge_type: CartridgeHeader::extract_cartridge_type(bytes),
+            rom_size,
+            rom_number_banks,
+            ram_size: CartridgeHeader::extract_ram_size(bytes),
+            destination_code: CartridgeHeader::extract_destination_code(bytes),
+            mask_rom_version_number: CartridgeHeader::extract_mask_rom_version_number(bytes),
+            header_checksum,
+            is_header_checksum_valid: CartridgeHeader::compute_header_checksum(bytes)
+                == header_checksum,
+            global_checksum,
+            is_global_checksum_valid: CartridgeHeader::compute_global_checksum(bytes)
+                == global_checksum,
+        }
+    }
+
+    pub fn empty() -> CartridgeHeader {
+        CartridgeHeader {
+            entry_point_values: [DEFAULT_BYTE; ENTRY_POINT.size],
+            title: "none".to_string(),
+            manufacturer_code: None,
+            cgb_support: CGBSupport::None,
+            licensee_code: "none".to_string(),
+            new_licensee_code: None,
+            supports_sgb: false,
+            cartridge_type: CartridgeType::ROM_Only,
+            rom_size: 0,
+            rom_number_banks: 0,
+            ram_size: 0,
+            destination_code: DestinationCode::Japan,
+            mask_rom_version_number: DEFAULT_BYTE,
+            header_checksum: DEFAULT_BYTE,
+            is_header_checksum_valid: false,
+            global_checksum: DEFAULT_WORD,
+            is_global_checksum_valid: false,
+        }
+    }
+
+    fn extract_entry_point_values(bytes: &[Byte]) -> [Byte; ENTRY_POINT.size] {
+        let mut entry_point_value: [Byte; ENTRY_POINT.size] = [DEFAULT_BYTE; ENTRY_POINT.size];
+        for address in ENTRY_POINT.start..=ENTRY_POINT.end {
+            entry_point_value[address - ENTRY_POINT.start] = bytes[address];
+        }
+        entry_point_value
+    }
+
+    fn extract_cgb_support(bytes: &[Byte]) -> CGBSupport {
+        match bytes[CGB_FLAG.offset] {
+            0x80 => CGBSupport::Enhanced,
+            0xC0 => CGBSupport::Only,
+            _ => CGBSupport::None,
+        }
+    }
+
+    fn extract_manufacturer_code(bytes: &[Byte], cgb_support: CGBSupport) -> Option<String> {
+        // Only CGB-era headers carve a 4-letter manufacturer code out of the title
+        if cgb_support == CGBSupport::None {
+            return None;
+        }
+
+        let code: &[Byte] = &bytes[MANUFACTURER_CODE.start..=MANUFACTURER_CODE.end];
+        if code
+            .iter()
+            .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
+        {
+            Some(String::from_utf8_lossy(code).to_string())
+        } else {
+            None
+        }
+    }
+
+    fn extract_title(
+        bytes: &[Byte],
+        cgb_support: CGBSupport,
+        has_manufacturer_code: bool,
+    ) -> String {
+        // 0x0143 holds the CGB flag and 0x013F-0x0142 the manufacturer code on newer carts
+        let end: Address = if has_manufacturer_code {
+            MANUFACTURER_CODE.start
+        } else if cgb_support != CGBSupport::None {
+            CGB_FLAG.offset
+        } else {
+            TITLE.end + 1
+        };
+
+        bytes[TITLE.start..end]
+            .iter()
+            .take_while(|&&byte| byte != 0)
+            .map(|&byte| byte as char)
+            .collect()
+    }
+
+    fn extract_new_licensee_code(bytes: &[Byte]) -> Option<String> {
+        if bytes[OLD_LICENSEE_CODE.offset] != 0x33 {
+            return None;
+        }
+
+        let code: &[Byte] = &bytes[NEW_LICENSEE_CODE.start..=NEW_LICENSEE_CODE.end];
+        Some(String::from_utf8_lossy(code).to_string())
+    }
+
+    fn extract_licensee_code(bytes: &[Byte]) -> String {
+        if bytes[OLD_LICENSEE_CODE.offset] != 0x33 {
+            match bytes[OLD_LICENSEE_CODE.offset] {
+                0x00 => "None".to_string(),
+                0x01 => "Nintendo".to_string(),
+                0x08 => "Capcom".to_string(),
+                0x09 => "HOT-B".to_string(),
+                0x0A => "Jaleco".to_string(),
+                0x0B => "Coconuts Japan".to_string(),
+                0x0C => "Elite Systems".to_string(),
+                0x13 => "EA (Electronic Arts)".to_string(),
+                0x18 => "Hudson Soft".to_string(),
+                0x19 => "ITC Entertainment".to_string(),
+                0x1A => "Yanoman".to_string(),
+                0x1D => "Japan Clary".to_string(),
+                0x1F => "Virgin Games Ltd.".to_string(),
+                0x24 => "PCM Complete".to_string(),
+                0x25 => "San-X".to_string(),
+                0x28 => "Kemco".to_string(),
+                0x29 => "SETA Corporation".to_string(),
+                0x30 => "Infogrames".to_string(),
+                0x31 => "Nintendo".to_string(),
+                0x32 => "Bandai".to_string(),
+                0x34 => "Konami".to_string(),
+                0x35 => "HectorSoft".to_string(),
+                0x38 => "Capcom".to_string(),
+                0x39 => "Banpresto".to_string(),
+                0x3C => "Entertainment Interactive".to_string(),
+                0x3E => "Gremlin".to_string(),
+                0x41 => "Ubi Soft".to_string(),
+                0x42 => "Atlus".to_string(),
+                0x44 => "Malibu Interactive".to_string(),
+                0x46 => "Angel".to_string(),
+                0x47 => "Spectrum HoloByte".to_string(),
+                0x49 => "Irem".to_string(),
+                0x4A => "Virgin Games Ltd.".to_string(),
+                0x4D => "Malibu Interactive".to_string(),
+                0x4F => "U.S. Gold".to_string(),
+                0x50 => "Absolute".to_string(),
+                0x51 => "Acclaim Entertainment".to_string(),
+                0x52 => "Activision".to_string(),
+                0x53 => "Sammy USA Corporation".to_string(),
+                0x54 => "GameTek".to_string(),
+                0x55 => "Park Place".to_string(),
+                0x56 => "LJN".to_string(),
+                0x57 => "Matchbox".to_string(),
+                0x59 => "Milton Bradley Company".to_string(),
+                0x5A => "Mindscape".to_string(),
+                0x5B => "Romstar".to_string(),
+                0x5C => "Naxat Soft".to_string(),
+                0x5D => "Tradewest".to_string(),
+                0x60 => "Titus Interactive".to_string(),
+                0x61 => "Virgin Games Ltd.".to_string(),
+                0x67 => "Ocean Software".to_string(),
+                0x69 => "EA (Electronic Arts)".to_string(),
+                0x6E => "Elite Systems".to_string(),
+                0x6F => "Electro Brain".to_string(),
+                0x70 => "Infogrames".to_string(),
+                0x71 => "Interplay Entertainment".to_string(),
+                0x72 => "Broderbund".to_string(),
+                0x73 => "Sculptured Software".to_string(),
+                0x75 => "The Sales Curve Limited".to_string(),
+                0x78 => "THQ".to_string(),
+                0x79 => "Accolade".to_string(),
+                0x7A => "Triffix Entertainment".to_string(),
+                0x7C => "MicroProse".to_string(),
+                0x7F => "Kemco".to_string(),
+                0x80 => "Misawa Entertainment".to_string(),
+                0x83 => "LOZC G.".to_string(),
+                0x86 => "Tokuma Shoten".to_string(),
+                0x8B => "Bullet-Proof Software".to_string(),
+                0x8C => "Vic Tokai Corp.".to_string(),
+                0x8E => "Ape Inc.".to_string(),
+                0x8F => "I’Max".to_string(),
+                0x91 => "Chunsoft Co.".to_string(),
+                0x92 => "Video System".to_string(),
+                0x93 => "Tsubaraya Productions".to_string(),
+                0x95 => "Varie".to_string(),
+                0x96 => "Yonezawa/S’Pal".to_string(),
+                0x97 => "Kemco".to_string(),
+                0x99 => "Arc".to_string(),
+                0x9A => "Nihon Bussan".to_string(),
+                0x9B => "Tecmo".to_string(),
+                0x9C => "Imagineer".to_string(),
+                0x9D => "Banpresto".to_string(),
+                0x9F => "Nova".to_string(),
+                0xA1 => "Hori Electric".to_string(),
+                0xA2 => "Bandai".to_string(),
+                0xA4 => "Konami".to_string(),
+                0xA6 => "Kawada".to_string(),
+                0xA7 => "Takara".to_string(),
+                0xA9 => "Technos Japan".to_string(),
+                0xAA => "Broderbund".to_string(),
+                0xAC => "Toei Animation".to_string(),
+                0xAD => "Toho".to_string(),
+                0xAF => "Namco".to_string(),
+                0xB0 => "Acclaim Entertainment".to_string(),
+                0xB1 => "ASCII Corporation or Nexsoft".to_string(),
+                0xB2 => "Bandai".to_string(),
+                0xB4 => "Square Enix".to_string(),
+                0xB6 => "HAL Laboratory".to_string(),
+                0xB7 => "SNK".to_string(),
+                0xB9 => "Pony Canyon".to_string(),
+                0xBA => "Culture Brain".to_string(),
+                0xBB => "Sunsoft".to_string(),
+                0xBD => "Sony Imagesoft".to_string(),
+                0xBF => "Sammy Corporation".to_string(),
+                0xC0 => "Taito".to_string(),
+                0xC2 => "Kemco".to_string(),
+                0xC3 => "Square".to_string(),
+                0xC4 => "Tokuma Shoten".to_string(),
+                0xC5 => "Data East".to_string(),
+                0xC6 => "Tonkin House".to_string(),
+                0xC8 => "Koei".to_string(),
+                0xC9 => "UFL".to_string(),
+                0xCA => "Ultra Games".to_string(),
+                0xCB => "VAP, Inc.".to_string(),
+                0xCC => "Use Corporation".to_string(),
+                0xCD => "Meldac".to_string(),
+                0xCE => "Pony Canyon".to_string(),
+                0xCF => "Angel".to_string(),
+                0xD0 => "Taito".to_string(),
+                0xD1 => "SOFEL (Software Engineering Lab)".to_string(),
+                0xD2 => "Quest".to_string(),
+                0xD3 => "Sigma Enterprises".to_string(),
+                0xD4 => "ASK Kodansha Co.".to_string(),
+                0xD6 => "Naxat Soft".to_string(),
+                0xD7 => "Copya System".to_string(),
+                0xD9 => "Banpresto".to_string(),
+                0xDA => "Tomy".to_string(),
+                0xDB => "LJN".to_string(),
+                0xDD => "Nippon Computer Systems".to_string(),
+                0xDE => "Human Ent.".to_string(),
+                0xDF => "Altron".to_string(),
+                0xE0 => "Jaleco".to_string(),
+                0xE1 => "Towa Chiki".to_string(),
+                0xE2 => "Yutaka".to_string(),
+                0xE3 => "Varie".to_string(),
+                0xE5 => "Epoch".to_string(),
+                0xE7 => "Athena".to_string(),
+                0xE8 => "Asmik Ace Entertainment".to_string(),
+                0xE9 => "Natsume".to_string(),
+                0xEA => "King Records".to_string(),
+                0xEB => "Atlus".to_string(),
+                0xEC => "Epic/Sony Records".to_string(),
+                0xEE => "IGS".to_string(),
+                0xF0 => "A Wave".to_string(),
+                0xF3 => "Extreme Entertainment".to_string(),
+                0xFF => "LJN".to_string(),
+                other => format!("Unknown (0x{:02X})", other),
+            }
+        } else {
+            let ascii_licensee_code =
+                std::str::from_utf8(&bytes[NEW_LICENSEE_CODE.start..=NEW_LICENSEE_CODE.end])
+                    .unwrap_or("00");
+            match ascii_licensee_code {
+                "00" => "None".to_string(),
+                "01" => "Nintendo Research & Development 1".to_string(),
+                "08" => "Capcom".to_string(),
+                "13" => "EA (Electronic Arts)".to_string(),
+                "18" => "Hudson Soft".to_string(),
+                "19" => "B-AI".to_string(),
+                "20" => "KSS".to_string(),
+                "22" => "Planning Office WADA".to_string(),
+                "24" => "PCM Complete".to_string(),
+                "25" => "San-X".to_string(),
+                "28" => "Kemco".to_string(),
+                "29" => "SETA Corporation".to_string(),
+                "30" => "Viacom".to_string(),
+                "31" => "Nintendo".to_string(),
+                "32" => "Bandai".to_string(),
+                "33" => "Ocean Software/Acclaim Entertainment".to_string(),
+                "34" => "Konami".to_string(),
+                "35" => "HectorSoft".to_string(),
+                "37" => "Taito".to_string(),
+                "38" => "Hudson Soft".to_string(),
+                "39" => "Banpresto".to_string(),
+                "41" => "Ubi Soft".to_string(),
+                "42" => "Atlus".to_string(),
+                "44" => "Malibu Interactive".to_string(),
+                "46" => "Angel".to_string(),
+                "47" => "Bullet-Proof Software".to_string(),
+                "49" => "Irem".to_string(),
+                "50" => "Absolute".to_string(),
+                "51" => "Acclaim Entertainment".to_string(),
+                "52" => "Activision".to_string(),
+                "53" => "Sammy USA Corporation".to_string(),
+                "54" => "Konami".to_string(),
+                "55" => "Hi Tech Expressions".to_string(),
+                "56" => "LJN".to_string(),
+                "57" => "Matchbox".to_string(),
+                "58" => "Mattel".to_string(),
+                "59" => "Milton Bradley Company".to_string(),
+                "60" => "Titus Interactive".to_string(),
+                "61" => "Virgin Games Ltd.".to_string(),
+                "64" => "Lucasfilm Games".to_string(),
+                "67" => "Ocean Software".to_string(),
+                "69" => "EA (Electronic Arts)".to_string(),
+                "70" => "Infogrames".to_string(),
+                "71" => "Interplay Entertainment".to_string(),
+                "72" => "Broderbund".to_string(),
+                "73" => "Sculptured Software".to_string(),
+                "75" => "The Sales Curve Limited".to_string(),
+                "78" => "THQ".to_string(),
+                "79" => "Accolade".to_string(),
+                "80" => "Misawa Entertainment".to_string(),
+                "83" => "LOZC G.".to_string(),
+                "86" => "Tokuma Shoten".to_string(),
+                "87" => "Tsukuda Original".to_string(),
+                "91" => "Chunsoft Co.".to_string(),
+                "92" => "Video System".to_string(),
+                "93" => "Ocean Software/Acclaim Entertainment".to_string(),
+                "95" => "Varie".to_string(),
+                "96" => "Yonezawa/S’Pal".to_string(),
+                "97" => "Kaneko".to_string(),
+                "99" => "Pack-In-Video".to_string(),
+                "9H" => "Bottom Up".to_string(),
+                "A4" => "Konami (Yu-Gi-Oh!)".to_string(),
+                "BL" => "MTO".to_string(),
+                "DK" => "Kodansha".to_string(),
+                other => other.to_string(),
+            }
+        }
+    }
+
+    fn extract_sgb_flag(bytes: &[Byte]) -> bool {
+        if bytes[SGB_FLAG.offset] == 0x03 {
+            return true;
+        }
+        false
+    }
+
+    fn extract_cartridge_type(bytes: &[Byte]) -> CartridgeType {
+        match bytes[CARTRIDGE_TYPE.offset] {
+            0x00 => CartridgeType::ROM_Only,
+            0x01 => CartridgeType::MBC1,
+            0x02 => CartridgeType::MBC1_RAM,
+            0x03 => CartridgeType::MBC1_RAM_Battery,
+            0x05 => CartridgeType::MBC2,
+            0x06 => CartridgeType::MBC2_Battery,
+            0x08 => CartridgeType::ROM_RAM,
+            0x09 => CartridgeType::ROM_RAM_Battery,
+            0x0B => CartridgeType::MMM01,
+            0x0C => CartridgeType::MMM01_RAM,
+            0x0D => CartridgeType::MMM01_RAM_Battery,
+            0x0F => CartridgeType::MBC3_Timer_Battery,
+            0x10 => CartridgeType::MBC3_Timer_RAM_Battery,
+            0x11 => CartridgeType::MBC3,
+            0x12 => CartridgeType::MBC3_RAM,
+            0x13 => CartridgeType::MBC3_RAM_Battery,
+            0x19 => CartridgeType::MBC5,
+            0x1A => CartridgeType::MBC5_RAM,
+            0x1B => CartridgeType::MBC5_RAM_Battery,
+            0x1C => CartridgeType::MBC5_Rumble,
+            0x1D => CartridgeType::MBC5_Rumble_RAM,
+            0x1E => CartridgeType::MBC5_Rumble_RAM_Battery,
+            0x1F => CartridgeType::Pocket_Camera,
+            0xFD => CartridgeType::Bandai_TAMA5,
+            0xFE => CartridgeType::HuC3,
+            0xFF => CartridgeType::HuC1_RAM_Battery,
+            other => CartridgeType::Unknown(other),
+        }
+    }
+
+    /// Returns the ROM size in bytes and its bank count, or `None` for an unknown code.
+    pub fn decode_rom_size(code: Byte) -> Option<(Size, usize)> {
+        match code {
+            0x00 => Some((32 * 1024, 2)),
+            0x01 => Some((64 * 1024, 4)),
+            0x02 => Some((128 * 1024, 8)),
+            0x03 => Some((256 * 1024, 16)),
+            0x04 => Some((512 * 1024, 32)),
+            0x05 => Some((1 * 1024 * 1024, 64)),
+            0x06 => Some((2 * 1024 * 1024, 128)),
+            0x07 => Some((4 * 1024 * 1024, 256)),
+            0x08 => Some((8 * 1024 * 1024, 512)),
+            0x52 => Some((1152 * 1024, 72)),
+            0x53 => Some((1280 * 1024, 80)),
+            0x54 => Some((1536 * 1024, 96)),
+            _ => None,
+        }
+    }
+
+    fn extract_ram_size(bytes: &[Byte]) -> usize {
+        match bytes[RAM_SIZE.offset] {
+            0x00 => 0,
+            0x01 => 2 * 1024,
+            0x02 => 8 * 1024,
+            0x03 => 32 * 1024,
+            0x04 => 128 * 1024,
+            0x05 => 64 * 1024,
+            _ => 0,
+        }
+    }
+
+    fn extract_destination_code(bytes: &[Byte]) -> DestinationCode {
+        match bytes[DESTINATION_CODE.offset] {
+            0x00 => DestinationCode::Japan,
+            0x01 => DestinationCode::Overseas,
+            other => DestinationCode::Unknown(other),
+        }
+    }
+
+    fn extract_mask_rom_version_number(bytes: &[Byte]) -> Byte {
+        bytes[MASK_ROM_VERSION.offset]
+    }
+
+    pub fn compute_header_checksum(bytes: &[Byte]) -> Byte {
+        let mut checksum: Byte = DEFAULT_BYTE;
+        for address in TITLE.start..=MASK_ROM_VERSION.offset {
+            checksum = checksum.wrapping_sub(bytes[address]).wrapping_sub(1);
+        }
+        checksum
+    }
+
+    pub fn compute_global_checksum(bytes: &[Byte]) -> Word {
+        let mut checksum: Word = DEFAULT_WORD;
+        for (address, byte) in bytes.iter().enumerate() {
+            if address < GLOBAL_CHECKSUM.start || address > GLOBAL_CHECKSUM.end {
+                checksum = checksum.wrapping_add(*byte as Word);
+            }
+        }
+        checksum
+    }
+
+    pub fn print_data(&self) {
+        print!("Entry Point Values: [");
+        for (i, byte) in self.entry_point_values.iter().enumerate() {
+            if i != 0 {
+                print!(", ");
+            }
+            print!("0x{:02X}", byte);
+        }
+        println!("]");
+        println!("Title: {}", self.title);
+        println!(
+            "Manufacturer Code: {}",
+            self.manufacturer_code.as_deref().unwrap_or("none")
+        );
+        println!("CGB Support: {:?}", self.cgb_support);
+        println!("Licensee Code: {}", self.licensee_code);
+        println!(
+            "New Licensee Code: {}",
+            self.new_licensee_code.as_deref().unwrap_or("none")
+        );
+        println!("Supports SGB: {}", self.supports_sgb);
+        println!("Cartridge Type: {:?}", self.cartridge_type);
+        println!("ROM Size: {} bytes", self.rom_size);
+        println!("ROM Number of Banks: {}", self.rom_number_banks);
+        println!("RAM Size: {} bytes", self.ram_size);
+        println!("Destination Code: {:?}", self.destination_code);
+        println!(
+            "Mask ROM Version Number: 0x{:02X}",
+            self.mask_rom_version_number
+        );
+        println!(
+            "Header Checksum: 0x{:02X} (valid: {})",
+            self.header_checksum, self.is_header_checksum_valid
+        );
+        println!(
+            "Global Checksum: 0x{:04X} (valid: {})",
+            self.global_checksum, self.is_global_checksum_valid
+        );
+        println!();
+    }
+}
@@ -0,0 +1,75 @@
+use crate::common::{
+    address::{RAM, ROM},
+    types::{Address, Byte},
+};
+
+pub const ROM_BANK_SIZE: Address = 0x4000;
+pub const RAM_BANK_SIZE: Address = 0x2000;
+
+pub trait MBC {
+    fn read_rom(&self, rom: &[Byte], address: Address) -> Byte;
+    fn write_rom(&mut self, address: Address, value: Byte);
+    fn read_ram(&self, ram: &[Byte], address: Address) -> Byte;
+    /// Returns whether the write reached RAM, so ignored writes don't dirty the save.
+    fn write_ram(&mut self, ram: &mut [Byte], address: Address, value: Byte) -> bool;
+
+    fn has_rtc(&self) -> bool {
+        false
+    }
+
+    fn save_rtc(&mut self) -> Option<Vec<Byte>> {
+        None
+    }
+
+    fn load_rtc(&mut self, _bytes: &[Byte]) {}
+
+    fn is_rumbling(&self) -> bool {
+        false
+    }
+}
+
+pub fn read_rom_bank(rom: &[Byte], bank: usize, address: Address) -> Byte {
+    let bank_count: usize = (rom.len() / ROM_BANK_SIZE).max(1);
+    let offset: Address = (bank % bank_count) * ROM_BANK_SIZE + (address % ROM_BANK_SIZE);
+    match rom.get(offset) {
+        Some(value) => *value,
+        None => 0xFF,
+    }
+}
+
+pub fn get_ram_offset(ram: &[Byte], bank: usize, address: Address) -> Option<Address> {
+    if ram.is_empty() {
+        return None;
+    }
+
+    // Chips smaller than the 8 KiB window are mirrored across it
+    Some((bank * RAM_BANK_SIZE + (address - RAM.start)) % ram.len())
+}
+
+pub struct NoMBC;
+
+impl MBC for NoMBC {
+    fn read_rom(&self, rom: &[Byte], address: Address) -> Byte {
+        match rom.get(address - ROM.start) {
+            Some(value) => *value,
+            None => 0xFF,
+        }
+    }
+
+    fn write_rom(&mut self, _address: Address, _value: Byte) {}
+
+    fn read_ram(&self, ram: &[Byte], address: Address) -> Byte {
+        match get_ram_offset(ram, 0, address) {
+            Some(offset) => ram[offset],
+            None => 0xFF,
+        }
+    }
+
+    fn write_ram(&mut self, ram: &mut [Byte], address: Address, value: Byte) -> bool {
+        if let Some(offset) = get_ram_offset(ram, 0, address) {
+            ram[offset] = value;
+            return true;
+        }
+        false
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    cartridge::mbc::{MBC, get_ram_offset, read_rom_bank},
+    common::types::{Address, Byte},
+};
+
+pub struct MBC1 {
+    ram_enabled: bool,
+    bank1: Byte,
+    bank2: Byte,
+    mode: Byte,
+    multicart: bool,
+}
+
+impl MBC1 {
+    pub fn new(multicart: bool) -> Self {
+        MBC1 {
+            ram_enabled: false,
+            bank1: 1,
+            bank2: 0,
+            mode: 0,
+            multicart,
+        }
+    }
+
+    fn get_bank2_shift(&self) -> usize {
+        // MBC1M boards wire BANK2 to ROM address lines 18-19 instead of 19-20
+        if self.multicart { 4 } else { 5 }
+    }
+
+    fn get_low_bank(&self) -> usize {
+        if self.mode == 1 {
+            (self.bank2 as usize) << self.get_bank2_shift()
+        } else {
+            0
+        }
+    }
+
+    fn get_high_bank(&self) -> usize {
+        let bank1: usize = if self.multicart {
+            (self.bank1 & 0x0F) as usize
+        } else {
+            self.bank1 as usize
+        };
+        ((self.bank2 as usize) << self.get_bank2_shift()) | bank1
+    }
+
+    fn get_ram_bank(&self) -> usize {
+        if self.mode == 1 {
+            self.bank2 as usize
+        } else {
+            0
+        }
+    }
+}
+
+impl MBC for MBC1 {
+    fn read_rom(&self, rom: &[Byte], address: Address) -> Byte {
+        if address < 0x4000 {
+            read_rom_bank(rom, self.get_low_bank(), address)
+        } else {
+            read_rom_bank(rom, self.get_high_bank(), address)
+        }
+    }
+
+    fn write_rom(&mut self, address: Address, value: Byte) {
+        if address < 0x2000 {
+            self.ram_enabled = (value & 0x0F) == 0x0A;
+        } else if address < 0x4000 {
+            // The zero check happens on the full 5-bit value, before any masking
+            let bank1: Byte = value & 0x1F;
+            self.bank1 = if bank1 == 0 { 1 } else { bank1 };
+        } else if address < 0x6000 {
+            self.bank2 = value & 0x03;
+        } else {
+            self.mode = value & 0x01;
+        }
+    }
+
+    fn read_ram(&self, ram: &[Byte], address: Address) -> Byte {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        match get_ram_offset(ram, self.get_ram_bank(), address) {
+            Some(offset) => ram[offset],
+            None => 0xFF,
+        }
+    }
+
+    fn write_ram(&mut self, ram: &mut [Byte], address: Address, value: Byte) -> bool {
+        if !self.ram_enabled {
+            return false;
+        }
+
+        if let Some(offset) = get_ram_offset(ram, self.get_ram_bank(), address) {
+            ram[offset] = value;
+            return true;
+        }
+        false
+    }
+}
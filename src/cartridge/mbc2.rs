@@ -0,0 +1,80 @@
+use crate::{
+    cartridge::mbc::{MBC, read_rom_bank},
+    common::{
+        address::RAM,
+        types::{Address, Byte, Size},
+    },
+};
+
+pub const MBC2_RAM_SIZE: Size = 512;
+
+pub struct MBC2 {
+    ram_enabled: bool,
+    rom_bank: Byte,
+}
+
+impl MBC2 {
+    pub fn new() -> Self {
+        MBC2 {
+            ram_enabled: false,
+            rom_bank: 1,
+        }
+    }
+
+    fn get_ram_offset(ram: &[Byte], address: Address) -> Option<Address> {
+        if ram.is_empty() {
+            return None;
+        }
+
+        // The 512 cells are mirrored across the whole 0xA000-0xBFFF window
+        Some((address - RAM.start) % MBC2_RAM_SIZE % ram.len())
+    }
+}
+
+impl MBC for MBC2 {
+    fn read_rom(&self, rom: &[Byte], address: Address) -> Byte {
+        if address < 0x4000 {
+            read_rom_bank(rom, 0, address)
+        } else {
+            read_rom_bank(rom, self.rom_bank as usize, address)
+        }
+    }
+
+    fn write_rom(&mut self, address: Address, value: Byte) {
+        if address >= 0x4000 {
+            return;
+        }
+
+        // Address bit 8 selects between RAM enable and the ROM bank register
+        if (address & 0x0100) == 0 {
+            self.ram_enabled = (value & 0x0F) == 0x0A;
+        } else {
+            let rom_bank: Byte = value & 0x0F;
+            self.rom_bank = if rom_bank == 0 { 1 } else { rom_bank };
+        }
+    }
+
+    fn read_ram(&self, ram: &[Byte], address: Address) -> Byte {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        // Only the low nibble is wired, the upper one reads back as 1s
+        match MBC2::get_ram_offset(ram, address) {
+            Some(offset) => ram[offset] | 0xF0,
+            None => 0xFF,
+        }
+    }
+
+    fn write_ram(&mut self, ram: &mut [Byte], address: Address, value: Byte) -> bool {
+        if !self.ram_enabled {
+            return false;
+        }
+
+        if let Some(offset) = MBC2::get_ram_offset(ram, address) {
+            ram[offset] = value & 0x0F;
+            return true;
+        }
+        false
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    cartridge::{
+        mbc::{MBC, get_ram_offset, read_rom_bank},
+        rtc::RealTimeClock,
+    },
+    common::types::{Address, Byte},
+};
+
+pub struct MBC3 {
+    ram_enabled: bool,
+    rom_bank: Byte,
+    ram_bank: Byte,
+    rtc: Option<RealTimeClock>,
+}
+
+impl MBC3 {
+    pub fn new(has_rtc: bool) -> Self {
+        MBC3 {
+            ram_enabled: false,
+            rom_bank: 1,
+            ram_bank: 0,
+            rtc: if has_rtc {
+                Some(RealTimeClock::new())
+            } else {
+                None
+            },
+        }
+    }
+
+    fn is_rtc_selected(&self) -> bool {
+        (0x08..=0x0C).contains(&self.ram_bank)
+    }
+}
+
+impl MBC for MBC3 {
+    fn read_rom(&self, rom: &[Byte], address: Address) -> Byte {
+        if address < 0x4000 {
+            read_rom_bank(rom, 0, address)
+        } else {
+            read_rom_bank(rom, self.rom_bank as usize, address)
+        }
+    }
+
+    fn write_rom(&mut self, address: Address, value: Byte) {
+        if address < 0x2000 {
+            self.ram_enabled = (value & 0x0F) == 0x0A;
+        } else if address < 0x4000 {
+            let rom_bank: Byte = value & 0x7F;
+            self.rom_bank = if rom_bank == 0 { 1 } else { rom_bank };
+        } else if address < 0x6000 {
+            self.ram_bank = value & 0x0F;
+        } else if let Some(rtc) = &mut self.rtc {
+            rtc.write_latch(value);
+        }
+    }
+
+    fn read_ram(&self, ram: &[Byte], address: Address) -> Byte {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        if self.is_rtc_selected() {
+            match &self.rtc {
+                Some(rtc) => rtc.read_register(self.ram_bank),
+                None => 0xFF,
+            }
+        } else {
+            match get_ram_offset(ram, (self.ram_bank & 0x03) as usize, address) {
+                Some(offset) => ram[offset],
+                None => 0xFF,
+            }
+        }
+    }
+
+    fn write_ram(&mut self, ram: &mut [Byte], address: Address, value: Byte) -> bool {
+        if !self.ram_enabled {
+            return false;
+        }
+
+        if self.is_rtc_selected() {
+            if let Some(rtc) = &mut self.rtc {
+                rtc.write_register(self.ram_bank, value);
+                return true;
+            }
+        } else if let Some(offset) = get_ram_offset(ram, (self.ram_bank & 0x03) as usize, address) {
+            ram[offset] = value;
+            return true;
+        }
+        false
+    }
+
+    fn has_rtc(&self) -> bool {
+        self.rtc.is_some()
+    }
+
+    fn save_rtc(&mut self) -> Option<Vec<Byte>> {
+        self.rtc.as_mut().map(|rtc| rtc.save())
+    }
+
+    fn load_rtc(&mut self, bytes: &[Byte]) {
+        if let Some(rtc) = &mut self.rtc {
+            rtc.load(bytes);
+        }
+    }
+}
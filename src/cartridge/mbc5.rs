@@ -0,0 +1,80 @@
+use crate::{
+    cartridge::mbc::{MBC, get_ram_offset, read_rom_bank},
+    common::types::{Address, Byte},
+};
+
+pub struct MBC5 {
+    ram_enabled: bool,
+    rom_bank: u16,
+    ram_bank: Byte,
+    has_rumble: bool,
+    rumbling: bool,
+}
+
+impl MBC5 {
+    pub fn new(has_rumble: bool) -> Self {
+        MBC5 {
+            ram_enabled: false,
+            rom_bank: 1,
+            ram_bank: 0,
+            has_rumble,
+            rumbling: false,
+        }
+    }
+}
+
+impl MBC for MBC5 {
+    fn read_rom(&self, rom: &[Byte], address: Address) -> Byte {
+        if address < 0x4000 {
+            read_rom_bank(rom, 0, address)
+        } else {
+            // Unlike MBC1 and MBC3, bank 0 can be mapped into the upper area
+            read_rom_bank(rom, self.rom_bank as usize, address)
+        }
+    }
+
+    fn write_rom(&mut self, address: Address, value: Byte) {
+        if address < 0x2000 {
+            self.ram_enabled = value == 0x0A;
+        } else if address < 0x3000 {
+            self.rom_bank = (self.rom_bank & 0x100) | value as u16;
+        } else if address < 0x4000 {
+            self.rom_bank = (self.rom_bank & 0xFF) | (((value & 0x01) as u16) << 8);
+        } else if address < 0x6000 {
+            // Rumble carts wire bit 3 of the RAM bank register to the motor
+            if self.has_rumble {
+                self.rumbling = (value & (1 << 3)) != 0;
+                self.ram_bank = value & 0x07;
+            } else {
+                self.ram_bank = value & 0x0F;
+            }
+        }
+    }
+
+    fn read_ram(&self, ram: &[Byte], address: Address) -> Byte {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        match get_ram_offset(ram, self.ram_bank as usize, address) {
+            Some(offset) => ram[offset],
+            None => 0xFF,
+        }
+    }
+
+    fn write_ram(&mut self, ram: &mut [Byte], address: Address, value: Byte) -> bool {
+        if !self.ram_enabled {
+            return false;
+        }
+
+        if let Some(offset) = get_ram_offset(ram, self.ram_bank as usize, address) {
+            ram[offset] = value;
+            return true;
+        }
+        false
+    }
+
+    fn is_rumbling(&self) -> bool {
+        self.rumbling
+    }
+}
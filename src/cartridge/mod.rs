@@ -1 +1,12 @@
+pub mod archive;
 pub mod cartridge;
+pub mod error;
+pub mod header;
+pub mod mbc;
+pub mod mbc1;
+pub mod mbc2;
+pub mod mbc3;
+pub mod mbc5;
+pub mod patch;
+pub mod rtc;
+pub mod save;
@@ -0,0 +1,521 @@
+use crate::{
+    cartridge::{
+        archive::{ARCHIVE_EXTENSIONS, ROM_EXTENSIONS, get_extension},
+        error::CartridgeError,
+    },
+    common::{
+        constant::MAX_ROM_SIZE,
+        types::{Address, Byte, Size},
+    },
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const PATCH_EXTENSIONS: [&str; 3] = ["ips", "ups", "bps"];
+
+/// Splits the opened path into the ROM to load and the patch to apply on top of it.
+///
+/// Opening a patch directly loads the ROM with the same name next to it, while
+/// opening a ROM picks up a patch with the same name if there is one.
+pub fn resolve_paths(path: &str) -> (PathBuf, Option<PathBuf>) {
+    let path: &Path = Path::new(path);
+    let extension: String = get_extension(path);
+
+    if PATCH_EXTENSIONS.contains(&extension.as_str()) {
+        for rom_extension in ROM_EXTENSIONS.iter().chain(ARCHIVE_EXTENSIONS.iter()) {
+            let rom_path: PathBuf = path.with_extension(rom_extension);
+            if rom_path.exists() {
+                return (rom_path, Some(path.to_path_buf()));
+            }
+        }
+        return (
+            path.with_extension(ROM_EXTENSIONS[0]),
+            Some(path.to_path_buf()),
+        );
+    }
+
+    for patch_extension in PATCH_EXTENSIONS {
+        let patch_path: PathBuf = path.with_extension(patch_extension);
+        if patch_path.exists() {
+            return (path.to_path_buf(), Some(patch_path));
+        }
+    }
+
+    (path.to_path_buf(), None)
+}
+
+pub fn apply_patch(rom: &[Byte], patch_path: &Path) -> Result<Vec<Byte>, CartridgeError> {
+    let patch: Vec<Byte> = match fs::read(patch_path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            return Err(CartridgeError::Unreadable {
+                path: patch_path.display().to_string(),
+                reason: error.to_string(),
+            });
+        }
+    };
+
+    let mut reader: PatchReader = PatchReader {
+        patch: &patch,
+        position: 0,
+        path: patch_path,
+    };
+
+    if patch.starts_with(b"PATCH") {
+        reader.position = 5;
+        apply_ips(rom, &mut reader)
+    } else if patch.starts_with(b"UPS1") {
+        reader.position = 4;
+        apply_ups(rom, &mut reader)
+    } else if patch.starts_with(b"BPS1") {
+        reader.position = 4;
+        apply_bps(rom, &mut reader)
+    } else {
+        Err(reader.error("format de patch inconnu".to_string()))
+    }
+}
+
+struct PatchReader<'a> {
+    patch: &'a [Byte],
+    position: Address,
+    path: &'a Path,
+}
+
+impl PatchReader<'_> {
+    fn error(&self, reason: String) -> CartridgeError {
+        CartridgeError::InvalidPatch {
+            path: self.path.display().to_string(),
+            reason,
+        }
+    }
+
+    fn read_byte(&mut self) -> Result<Byte, CartridgeError> {
+        match self.patch.get(self.position) {
+            Some(byte) => {
+                self.position += 1;
+                Ok(*byte)
+            }
+            None => Err(self.error("fin de fichier inattendue".to_string())),
+        }
+    }
+
+    fn read_bytes(&mut self, length: Size) -> Result<&[Byte], CartridgeError> {
+        if length > self.patch.len() - self.position {
+            return Err(self.error("fin de fichier inattendue".to_string()));
+        }
+
+        let bytes: &[Byte] = &self.patch[self.position..self.position + length];
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn read_big_endian(&mut self, length: Size) -> Result<Size, CartridgeError> {
+        let mut value: Size = 0;
+        for _ in 0..length {
+            value = (value << 8) | self.read_byte()? as Size;
+        }
+        Ok(value)
+    }
+
+    fn read_varint(&mut self) -> Result<Size, CartridgeError> {
+        // UPS and BPS encode numbers 7 bits at a time with an implicit offset per byte
+        let mut value: Size = 0;
+        let mut shift: Size = 1;
+        loop {
+            let byte: Byte = self.read_byte()?;
+            value = ((byte & 0x7F) as Size)
+                .checked_mul(shift)
+                .and_then(|digit| value.checked_add(digit))
+                .ok_or_else(|| self.error("nombre trop grand".to_string()))?;
+            if (byte & 0x80) != 0 {
+                return Ok(value);
+            }
+            shift = shift
+                .checked_mul(0x80)
+                .ok_or_else(|| self.error("nombre trop grand".to_string()))?;
+            value = value
+                .checked_add(shift)
+                .ok_or_else(|| self.error("nombre trop grand".to_string()))?;
+        }
+    }
+
+    fn check_target_size(&self, target_size: Size) -> Result<(), CartridgeError> {
+        if target_size > MAX_ROM_SIZE {
+            return Err(self.error(format!("ROM patchée trop grande ({} octets)", target_size)));
+        }
+        Ok(())
+    }
+
+    fn read_footer_crc(&self, index: Size) -> u32 {
+        let start: Address = self.patch.len() - 12 + index * 4;
+        u32::from_le_bytes(self.patch[start..start + 4].try_into().unwrap())
+    }
+
+    fn verify_footer(&self, source: &[Byte], target: &[Byte]) -> Result<(), CartridgeError> {
+        if self.patch.len() < 12 {
+            return Err(self.error("pied de patch manquant".to_string()));
+        }
+
+        let patch_crc: u32 = self.read_footer_crc(2);
+        if crc32(&self.patch[..self.patch.len() - 4]) != patch_crc {
+            return Err(self.error("le fichier de patch est corrompu".to_string()));
+        }
+
+        let source_crc: u32 = crc32(source);
+        if source_crc != self.read_footer_crc(0) {
+            return Err(self.error(format!(
+                "la ROM ne correspond pas au patch (CRC32 attendu {:08X}, obtenu {:08X})",
+                self.read_footer_crc(0),
+                source_crc
+            )));
+        }
+
+        if crc32(target) != self.read_footer_crc(1) {
+            return Err(self.error("la ROM patchée a un CRC32 inattendu".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+fn apply_ips(rom: &[Byte], reader: &mut PatchReader) -> Result<Vec<Byte>, CartridgeError> {
+    let mut output: Vec<Byte> = rom.to_vec();
+
+    loop {
+        let header: &[Byte] = reader.read_bytes(3)?;
+        if header == b"EOF" {
+            break;
+        }
+
+        let offset: Address =
+            ((header[0] as Address) << 16) | ((header[1] as Address) << 8) | header[2] as Address;
+        let length: Size = reader.read_big_endian(2)?;
+
+        // A zero length introduces a run-length encoded record
+        let data: Vec<Byte> = if length == 0 {
+            let count: Size = reader.read_big_endian(2)?;
+            vec![reader.read_byte()?; count]
+        } else {
+            reader.read_bytes(length)?.to_vec()
+        };
+
+        if output.len() < offset + data.len() {
+            output.resize(offset + data.len(), 0);
+        }
+        output[offset..offset + data.len()].copy_from_slice(&data);
+    }
+
+    // Some IPS patches append the final size of the image after the EOF marker
+    if reader.patch.len() - reader.position == 3 {
+        let size: Size = reader.read_big_endian(3)?;
+        output.truncate(size);
+    }
+
+    Ok(output)
+}
+
+fn apply_ups(rom: &[Byte], reader: &mut PatchReader) -> Result<Vec<Byte>, CartridgeError> {
+    let source_size: Size = reader.read_varint()?;
+    let target_size: Size = reader.read_varint()?;
+    if source_size != rom.len() {
+        return Err(reader.error(format!(
+            "la ROM ne correspond pas au patch (taille attendue {} octets, obtenue {})",
+            source_size,
+            rom.len()
+        )));
+    }
+    reader.check_target_size(target_size)?;
+
+    let mut output: Vec<Byte> = rom.to_vec();
+    output.resize(target_size, 0);
+
+    let end: Address = reader.patch.len().saturating_sub(12);
+    let mut position: Address = 0;
+    while reader.position < end {
+        position = position
+            .checked_add(reader.read_varint()?)
+            .ok_or_else(|| reader.error("décalage trop grand".to_string()))?;
+        loop {
+            let byte: Byte = reader.read_byte()?;
+            if byte == 0 {
+                position += 1;
+                break;
+            }
+            if position < output.len() {
+                output[position] ^= byte;
+            }
+            position += 1;
+        }
+    }
+
+    reader.verify_footer(rom, &output)?;
+    Ok(output)
+}
+
+fn apply_bps(rom: &[Byte], reader: &mut PatchReader) -> Result<Vec<Byte>, CartridgeError> {
+    let source_size: Size = reader.read_varint()?;
+    let target_size: Size = reader.read_varint()?;
+    let metadata_size: Size = reader.read_varint()?;
+    reader.read_bytes(metadata_size)?;
+
+    if source_size != rom.len() {
+        return Err(reader.error(format!(
+            "la ROM ne correspond pas au patch (taille attendue {} octets, obtenue {})",
+            source_size,
+            rom.len()
+        )));
+    }
+    reader.check_target_size(target_size)?;
+
+    let mut output: Vec<Byte> = Vec::with_capacity(target_size);
+    let mut source_offset: isize = 0;
+    let mut target_offset: isize = 0;
+
+    let end: Address = reader.patch.len().saturating_sub(12);
+    while reader.position < end {
+        let data: Size = reader.read_varint()?;
+        let command: Size = data & 0x03;
+        let length: Size = (data >> 2) + 1;
+        if length > target_size - output.len() {
+            return Err(reader.error("écriture au-delà de la ROM cible".to_string()));
+        }
+
+        match command {
+            0 => {
+                // SourceRead copies from the same offset in the original ROM
+                let start: Address = output.len();
+                if start > rom.len() || length > rom.len() - start {
+                    return Err(reader.error("lecture hors de la ROM source".to_string()));
+                }
+                output.extend_from_slice(&rom[start..start + length]);
+            }
+            1 => {
+                let bytes: &[Byte] = reader.read_bytes(length)?;
+                output.extend_from_slice(bytes);
+            }
+            2 => {
+                source_offset = source_offset
+                    .checked_add(read_relative_offset(reader)?)
+                    .ok_or_else(|| reader.error("copie hors de la ROM source".to_string()))?;
+                for _ in 0..length {
+                    match rom.get(source_offset as Address) {
+                        Some(byte) if source_offset >= 0 => output.push(*byte),
+                        _ => return Err(reader.error("copie hors de la ROM source".to_string())),
+                    }
+                    source_offset += 1;
+                }
+            }
+            _ => {
+                // TargetCopy may overlap the bytes it is producing, so copy one at a time
+                target_offset = target_offset
+                    .checked_add(read_relative_offset(reader)?)
+                    .ok_or_else(|| reader.error("copie hors de la ROM cible".to_string()))?;
+                for _ in 0..length {
+                    match output.get(target_offset as Address) {
+                        Some(byte) if target_offset >= 0 => output.push(*byte),
+                        _ => return Err(reader.error("copie hors de la ROM cible".to_string())),
+                    }
+                    target_offset += 1;
+                }
+            }
+        }
+    }
+
+    if output.len() != target_size {
+        return Err(reader.error(format!(
+            "taille de la ROM patchée incorrecte ({} octets au lieu de {})",
+            output.len(),
+            target_size
+        )));
+    }
+
+    reader.verify_footer(rom, &output)?;
+    Ok(output)
+}
+
+fn read_relative_offset(reader: &mut PatchReader) -> Result<isize, CartridgeError> {
+    let data: Size = reader.read_varint()?;
+    let offset: isize = (data >> 1) as isize;
+    if (data & 0x01) != 0 {
+        Ok(-offset)
+    } else {
+        Ok(offset)
+    }
+}
+
+fn crc32(bytes: &[Byte]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask: u32 = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(
+        rom: &[Byte],
+        patch: &[Byte],
+        apply: fn(&[Byte], &mut PatchReader) -> Result<Vec<Byte>, CartridgeError>,
+        header: Size,
+    ) -> Result<Vec<Byte>, CartridgeError> {
+        let mut reader: PatchReader = PatchReader {
+            patch,
+            position: header,
+            path: Path::new("test.patch"),
+        };
+        apply(rom, &mut reader)
+    }
+
+    fn varint(mut value: Size) -> Vec<Byte> {
+        let mut bytes: Vec<Byte> = Vec::new();
+        loop {
+            let digit: Byte = (value & 0x7F) as Byte;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(digit | 0x80);
+                return bytes;
+            }
+            bytes.push(digit);
+            value -= 1;
+        }
+    }
+
+    fn with_footer(mut patch: Vec<Byte>, source: &[Byte], target: &[Byte]) -> Vec<Byte> {
+        patch.extend_from_slice(&crc32(source).to_le_bytes());
+        patch.extend_from_slice(&crc32(target).to_le_bytes());
+        let patch_crc: u32 = crc32(&patch);
+        patch.extend_from_slice(&patch_crc.to_le_bytes());
+        patch
+    }
+
+    fn is_invalid(result: Result<Vec<Byte>, CartridgeError>) -> bool {
+        matches!(result, Err(CartridgeError::InvalidPatch { .. }))
+    }
+
+    #[test]
+    fn crc32_matches_reference() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn ips_applies_records_and_rle() {
+        let rom: Vec<Byte> = vec![0; 8];
+        let mut patch: Vec<Byte> = b"PATCH".to_vec();
+        patch.extend_from_slice(&[0x00, 0x00, 0x01, 0x00, 0x02, 0xAA, 0xBB]);
+        patch.extend_from_slice(&[0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x04, 0xCC]);
+        patch.extend_from_slice(b"EOF");
+
+        let output: Vec<Byte> = run(&rom, &patch, apply_ips, 5).unwrap();
+        assert_eq!(output, [0, 0xAA, 0xBB, 0, 0, 0, 0xCC, 0xCC, 0xCC, 0xCC]);
+    }
+
+    #[test]
+    fn ips_truncates_to_trailer_size() {
+        let rom: Vec<Byte> = vec![1; 8];
+        let mut patch: Vec<Byte> = b"PATCH".to_vec();
+        patch.extend_from_slice(b"EOF");
+        patch.extend_from_slice(&[0x00, 0x00, 0x04]);
+
+        assert_eq!(run(&rom, &patch, apply_ips, 5).unwrap(), [1; 4]);
+    }
+
+    #[test]
+    fn ips_rejects_truncated_file() {
+        let mut patch: Vec<Byte> = b"PATCH".to_vec();
+        patch.extend_from_slice(&[0x00, 0x00, 0x01, 0x00, 0x04, 0xAA]);
+
+        assert!(is_invalid(run(&[0; 8], &patch, apply_ips, 5)));
+    }
+
+    #[test]
+    fn ups_xors_runs_and_grows_target() {
+        let rom: Vec<Byte> = vec![0x10, 0x20, 0x30, 0x40];
+        let target: Vec<Byte> = vec![0x10, 0x21, 0x33, 0x40, 0x00, 0x55];
+
+        let mut patch: Vec<Byte> = b"UPS1".to_vec();
+        patch.extend(varint(rom.len()));
+        patch.extend(varint(target.len()));
+        patch.extend(varint(1));
+        patch.extend_from_slice(&[0x01, 0x03, 0x00]);
+        patch.extend(varint(1));
+        patch.extend_from_slice(&[0x55, 0x00]);
+        let patch: Vec<Byte> = with_footer(patch, &rom, &target);
+
+        assert_eq!(run(&rom, &patch, apply_ups, 4).unwrap(), target);
+    }
+
+    #[test]
+    fn ups_rejects_wrong_source_crc() {
+        let rom: Vec<Byte> = vec![0x10, 0x20];
+        let mut patch: Vec<Byte> = b"UPS1".to_vec();
+        patch.extend(varint(2));
+        patch.extend(varint(2));
+        let patch: Vec<Byte> = with_footer(patch, &[0x11, 0x20], &rom);
+
+        assert!(is_invalid(run(&rom, &patch, apply_ups, 4)));
+    }
+
+    #[test]
+    fn bps_applies_every_command() {
+        let rom: Vec<Byte> = vec![1, 2, 3, 4, 5, 6];
+        let target: Vec<Byte> = vec![1, 2, 9, 5, 6, 6, 6, 6, 6];
+
+        let mut patch: Vec<Byte> = b"BPS1".to_vec();
+        patch.extend(varint(rom.len()));
+        patch.extend(varint(target.len()));
+        patch.extend(varint(0));
+        // SourceRead 2, TargetRead 1, SourceCopy 2 from 4, TargetCopy 4 from 4 (overlapping)
+        patch.extend(varint(1 << 2));
+        patch.extend(varint(1));
+        patch.push(9);
+        patch.extend(varint((1 << 2) | 2));
+        patch.extend(varint(4 << 1));
+        patch.extend(varint((3 << 2) | 3));
+        patch.extend(varint(4 << 1));
+        let patch: Vec<Byte> = with_footer(patch, &rom, &target);
+
+        assert_eq!(run(&rom, &patch, apply_bps, 4).unwrap(), target);
+    }
+
+    #[test]
+    fn bps_rejects_corrupted_patch() {
+        let rom: Vec<Byte> = vec![1, 2];
+        let mut patch: Vec<Byte> = b"BPS1".to_vec();
+        patch.extend(varint(2));
+        patch.extend(varint(2));
+        patch.extend(varint(0));
+        patch.extend(varint(1 << 2));
+        let mut patch: Vec<Byte> = with_footer(patch, &rom, &rom);
+        let last: Size = patch.len() - 1;
+        patch[last] ^= 0xFF;
+
+        assert!(is_invalid(run(&rom, &patch, apply_bps, 4)));
+    }
+
+    #[test]
+    fn garbage_sizes_are_errors() {
+        let mut overflow: Vec<Byte> = b"BPS1".to_vec();
+        overflow.extend_from_slice(&[0x7F; 16]);
+        assert!(is_invalid(run(&[0; 2], &overflow, apply_bps, 4)));
+
+        let mut metadata: Vec<Byte> = b"BPS1".to_vec();
+        metadata.extend(varint(2));
+        metadata.extend(varint(2));
+        metadata.extend(varint(Size::MAX >> 8));
+        assert!(is_invalid(run(&[0; 2], &metadata, apply_bps, 4)));
+
+        let mut huge: Vec<Byte> = b"UPS1".to_vec();
+        huge.extend(varint(2));
+        huge.extend(varint(Size::MAX >> 8));
+        assert!(is_invalid(run(&[0; 2], &huge, apply_ups, 4)));
+    }
+}
@@ -0,0 +1,178 @@
+use crate::common::types::{Byte, Size};
+use chrono::Utc;
+
+pub const RTC_FOOTER_SIZE: Size = 48;
+
+#[derive(Clone, Copy)]
+struct ClockRegisters {
+    seconds: Byte,
+    minutes: Byte,
+    hours: Byte,
+    days: u16,
+    halted: bool,
+    carry: bool,
+}
+
+impl ClockRegisters {
+    fn new() -> Self {
+        ClockRegisters {
+            seconds: 0,
+            minutes: 0,
+            hours: 0,
+            days: 0,
+            halted: false,
+            carry: false,
+        }
+    }
+
+    fn read(&self, register: Byte) -> Byte {
+        match register {
+            0x08 => self.seconds,
+            0x09 => self.minutes,
+            0x0A => self.hours,
+            0x0B => (self.days & 0xFF) as Byte,
+            0x0C => self.get_day_high(),
+            _ => 0xFF,
+        }
+    }
+
+    fn write(&mut self, register: Byte, value: Byte) {
+        match register {
+            0x08 => self.seconds = value & 0x3F,
+            0x09 => self.minutes = value & 0x3F,
+            0x0A => self.hours = value & 0x1F,
+            0x0B => self.days = (self.days & 0x100) | value as u16,
+            0x0C => {
+                self.days = (self.days & 0xFF) | (((value & 0x01) as u16) << 8);
+                self.halted = (value & (1 << 6)) != 0;
+                self.carry = (value & (1 << 7)) != 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_day_high(&self) -> Byte {
+        let mut value: Byte = ((self.days >> 8) & 0x01) as Byte;
+        if self.halted {
+            value |= 1 << 6;
+        }
+        if self.carry {
+            value |= 1 << 7;
+        }
+        value
+    }
+
+    fn advance(&mut self, seconds: u64) {
+        let total_seconds: u64 = self.seconds as u64 + seconds;
+        self.seconds = (total_seconds % 60) as Byte;
+
+        let total_minutes: u64 = self.minutes as u64 + total_seconds / 60;
+        self.minutes = (total_minutes % 60) as Byte;
+
+        let total_hours: u64 = self.hours as u64 + total_minutes / 60;
+        self.hours = (total_hours % 24) as Byte;
+
+        // The day counter is 9 bits wide and sets the sticky carry flag on overflow
+        let total_days: u64 = self.days as u64 + total_hours / 24;
+        if total_days > 0x1FF {
+            self.carry = true;
+        }
+        self.days = (total_days % 0x200) as u16;
+    }
+
+    fn to_footer(&self, bytes: &mut Vec<Byte>) {
+        let values: [Byte; 5] = [
+            self.seconds,
+            self.minutes,
+            self.hours,
+            (self.days & 0xFF) as Byte,
+            self.get_day_high(),
+        ];
+        for value in values {
+            bytes.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+    }
+
+    fn from_footer(bytes: &[Byte]) -> ClockRegisters {
+        let mut registers: ClockRegisters = ClockRegisters::new();
+        for (index, register) in (0x08..=0x0C).enumerate() {
+            registers.write(register, bytes[index * 4]);
+        }
+        registers
+    }
+}
+
+pub struct RealTimeClock {
+    current: ClockRegisters,
+    latched: ClockRegisters,
+    latch_armed: bool,
+    last_update: i64,
+}
+
+impl RealTimeClock {
+    pub fn new() -> Self {
+        RealTimeClock {
+            current: ClockRegisters::new(),
+            latched: ClockRegisters::new(),
+            latch_armed: false,
+            last_update: Utc::now().timestamp(),
+        }
+    }
+
+    fn update(&mut self) {
+        let now: i64 = Utc::now().timestamp();
+        if !self.current.halted && now > self.last_update {
+            self.current.advance((now - self.last_update) as u64);
+        }
+        self.last_update = now;
+    }
+
+    pub fn write_latch(&mut self, value: Byte) {
+        // Writing 0x00 then 0x01 copies the running clock into the readable registers
+        if self.latch_armed && value == 0x01 {
+            self.update();
+            self.latched = self.current;
+        }
+        self.latch_armed = value == 0x00;
+    }
+
+    pub fn read_register(&self, register: Byte) -> Byte {
+        self.latched.read(register)
+    }
+
+    pub fn write_register(&mut self, register: Byte, value: Byte) {
+        self.update();
+        self.current.write(register, value);
+        self.latched.write(register, value);
+    }
+
+    /// Serializes the clock in the 48-byte footer layout shared with VBA-M and BGB.
+    pub fn save(&mut self) -> Vec<Byte> {
+        self.update();
+
+        let mut bytes: Vec<Byte> = Vec::with_capacity(RTC_FOOTER_SIZE);
+        self.current.to_footer(&mut bytes);
+        self.latched.to_footer(&mut bytes);
+        bytes.extend_from_slice(&(self.last_update as u64).to_le_bytes());
+        bytes
+    }
+
+    pub fn load(&mut self, bytes: &[Byte]) {
+        if bytes.len() < 44 {
+            return;
+        }
+
+        self.current = ClockRegisters::from_footer(&bytes[0..20]);
+        self.latched = ClockRegisters::from_footer(&bytes[20..40]);
+
+        // Some emulators only store a 32-bit timestamp
+        self.last_update = if bytes.len() >= RTC_FOOTER_SIZE {
+            u64::from_le_bytes(bytes[40..48].try_into().unwrap()) as i64
+        } else {
+            u32::from_le_bytes(bytes[40..44].try_into().unwrap()) as i64
+        };
+
+        // Catch up with the time spent while the emulator was closed
+        self.update();
+    }
+}
@@ -0,0 +1,48 @@
+use crate::common::types::Byte;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to a directory where saves are kept instead of next to the ROM.
+pub const SAVES_DIRECTORY_VARIABLE: &str = "GBRS_SAVES_DIR";
+
+pub fn get_save_path(rom_path: &str) -> PathBuf {
+    let rom_path: &Path = Path::new(rom_path);
+    let save_path: PathBuf = rom_path.with_extension("sav");
+
+    match env::var(SAVES_DIRECTORY_VARIABLE) {
+        Ok(directory) if !directory.is_empty() => match save_path.file_name() {
+            Some(file_name) => Path::new(&directory).join(file_name),
+            None => save_path,
+        },
+        _ => save_path,
+    }
+}
+
+pub fn read_save(path: &Path) -> Option<Vec<Byte>> {
+    match fs::read(path) {
+        Ok(bytes) => Some(bytes),
+        Err(_e) => None,
+    }
+}
+
+pub fn write_save(path: &Path, bytes: &[Byte]) {
+    if let Some(directory) = path.parent() {
+        if !directory.as_os_str().is_empty() {
+            let _ = fs::create_dir_all(directory);
+        }
+    }
+
+    // Write to a temporary file first so a crash never leaves a truncated save
+    let temporary_path: PathBuf = path.with_extension("sav.tmp");
+    let result = fs::write(&temporary_path, bytes).and_then(|_| fs::rename(&temporary_path, path));
+
+    if let Err(error) = result {
+        eprintln!(
+            "Impossible d'écrire la sauvegarde {} : {}",
+            path.display(),
+            error
+        );
+    }
+}
@@ -89,6 +89,7 @@ pub const MANUFACTURER_CODE: AddressRange = AddressRange {
     end: 0x0142,
     size: 0x0142 - 0x013F + 1,
 };
+pub const CGB_FLAG: AddressOffset = AddressOffset { offset: 0x0143 };
 pub const NEW_LICENSEE_CODE: AddressRange = AddressRange {
     start: 0x0144,
     end: 0x0145,
@@ -108,6 +109,47 @@ pub const GLOBAL_CHECKSUM: AddressRange = AddressRange {
     size: 0x014F - 0x014E + 1,
 };
 
+/* === IO registers ===  */
+pub const P1: AddressOffset = AddressOffset { offset: 0xFF00 };
+pub const DIV: AddressOffset = AddressOffset { offset: 0xFF04 };
+pub const TIMA: AddressOffset = AddressOffset { offset: 0xFF05 };
+pub const TMA: AddressOffset = AddressOffset { offset: 0xFF06 };
+pub const TAC: AddressOffset = AddressOffset { offset: 0xFF07 };
+pub const IF: AddressOffset = AddressOffset { offset: 0xFF0F };
+pub const NR10: AddressOffset = AddressOffset { offset: 0xFF10 };
+pub const NR11: AddressOffset = AddressOffset { offset: 0xFF11 };
+pub const NR12: AddressOffset = AddressOffset { offset: 0xFF12 };
+pub const NR13: AddressOffset = AddressOffset { offset: 0xFF13 };
+pub const NR14: AddressOffset = AddressOffset { offset: 0xFF14 };
+pub const NR21: AddressOffset = AddressOffset { offset: 0xFF16 };
+pub const NR22: AddressOffset = AddressOffset { offset: 0xFF17 };
+pub const NR23: AddressOffset = AddressOffset { offset: 0xFF18 };
+pub const NR24: AddressOffset = AddressOffset { offset: 0xFF19 };
+pub const NR30: AddressOffset = AddressOffset { offset: 0xFF1A };
+pub const NR31: AddressOffset = AddressOffset { offset: 0xFF1B };
+pub const NR32: AddressOffset = AddressOffset { offset: 0xFF1C };
+pub const NR33: AddressOffset = AddressOffset { offset: 0xFF1D };
+pub const NR34: AddressOffset = AddressOffset { offset: 0xFF1E };
+pub const NR41: AddressOffset = AddressOffset { offset: 0xFF20 };
+pub const NR42: AddressOffset = AddressOffset { offset: 0xFF21 };
+pub const NR43: AddressOffset = AddressOffset { offset: 0xFF22 };
+pub const NR44: AddressOffset = AddressOffset { offset: 0xFF23 };
+pub const NR50: AddressOffset = AddressOffset { offset: 0xFF24 };
+pub const NR51: AddressOffset = AddressOffset { offset: 0xFF25 };
+pub const NR52: AddressOffset = AddressOffset { offset: 0xFF26 };
+pub const LCDC: AddressOffset = AddressOffset { offset: 0xFF40 };
+pub const STAT: AddressOffset = AddressOffset { offset: 0xFF41 };
+pub const SCY: AddressOffset = AddressOffset { offset: 0xFF42 };
+pub const SCX: AddressOffset = AddressOffset { offset: 0xFF43 };
+pub const LY: AddressOffset = AddressOffset { offset: 0xFF44 };
+pub const LYC: AddressOffset = AddressOffset { offset: 0xFF45 };
+pub const DMA: AddressOffset = AddressOffset { offset: 0xFF46 };
+pub const BGP: AddressOffset = AddressOffset { offset: 0xFF47 };
+pub const OBP0: AddressOffset = AddressOffset { offset: 0xFF48 };
+pub const OBP1: AddressOffset = AddressOffset { offset: 0xFF49 };
+pub const WY: AddressOffset = AddressOffset { offset: 0xFF4A };
+pub const WX: AddressOffset = AddressOffset { offset: 0xFF4B };
+
 /* ===  ===  */
 pub const TILE_SET: AddressRange = AddressRange {
     start: 0x8000,
@@ -115,8 +157,45 @@ pub const TILE_SET: AddressRange = AddressRange {
     size: 0x97FF - 0x8000 + 1,
 };
 
+pub const TILE_SET_SIGNED_BASE: AddressOffset = AddressOffset { offset: 0x9000 };
+
 pub const TILE_MAP: AddressRange = AddressRange {
     start: 0x9800,
     end: 0x9FFF,
     size: 0x9FFF - 0x9800 + 1,
 };
+
+pub const TILE_MAP_LOW: AddressOffset = AddressOffset { offset: 0x9800 };
+pub const TILE_MAP_HIGH: AddressOffset = AddressOffset { offset: 0x9C00 };
+
+/* === Audio ===  */
+pub const AUDIO: AddressRange = AddressRange {
+    start: 0xFF10,
+    end: 0xFF3F,
+    size: 0xFF3F - 0xFF10 + 1,
+};
+pub const SQUARE1_REGISTERS: AddressRange = AddressRange {
+    start: 0xFF10,
+    end: 0xFF14,
+    size: 0xFF14 - 0xFF10 + 1,
+};
+pub const SQUARE2_REGISTERS: AddressRange = AddressRange {
+    start: 0xFF15,
+    end: 0xFF19,
+    size: 0xFF19 - 0xFF15 + 1,
+};
+pub const WAVE_REGISTERS: AddressRange = AddressRange {
+    start: 0xFF1A,
+    end: 0xFF1E,
+    size: 0xFF1E - 0xFF1A + 1,
+};
+pub const NOISE_REGISTERS: AddressRange = AddressRange {
+    start: 0xFF1F,
+    end: 0xFF23,
+    size: 0xFF23 - 0xFF1F + 1,
+};
+pub const WAVE_RAM: AddressRange = AddressRange {
+    start: 0xFF30,
+    end: 0xFF3F,
+    size: 0xFF3F - 0xFF30 + 1,
+};
@@ -1,4 +1,27 @@
-use crate::common::types::{Byte, Word};
+use crate::common::types::{Byte, Cycles, Size, Word};
 
 pub const DEFAULT_BYTE: Byte = 0x00;
 pub const DEFAULT_WORD: Word = 0x0000;
+
+pub const CYCLES_PER_FRAME: Cycles = 70224;
+pub const DOTS_PER_SCANLINE: Cycles = 456;
+pub const OAM_SCAN_DOTS: Cycles = 80;
+pub const DRAWING_DOTS: Cycles = 172;
+pub const SCANLINES_PER_FRAME: Byte = 154;
+pub const MAX_FRAME_LAG: u32 = 3;
+pub const VBLANK_START_LINE: Byte = 144;
+
+pub const SCREEN_WIDTH: Size = 160;
+pub const SCREEN_HEIGHT: Size = 144;
+
+pub const SPRITE_COUNT: Size = 40;
+pub const MAX_SPRITES_PER_LINE: Size = 10;
+
+pub const SAVE_FLUSH_FRAMES: u32 = 300;
+pub const MAX_ROM_SIZE: Size = 8 * 1024 * 1024;
+
+pub const CPU_FREQUENCY: u32 = 4_194_304;
+pub const FRAME_SEQUENCER_PERIOD: Cycles = 8192;
+pub const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+pub const AUDIO_BUFFER_MS: u32 = 100;
+pub const MAX_RATE_DELTA: f64 = 0.005;
@@ -2,6 +2,7 @@ pub type Address = usize;
 pub type Byte = u8;
 pub type Word = u16;
 pub type Size = usize;
+pub type Cycles = u32;
 pub type Register8 = u8;
 pub type Register16 = u16;
 pub type n8 = i8;
@@ -1,9 +1,10 @@
 use crate::{
-    common::types::{Address, Byte, u3},
+    common::types::{Address, Byte, Cycles, u3},
     cpu::registers::{Registers, Registers8, Registers16},
     mmu::mmu::MMU,
 };
 
+#[derive(Copy, Clone)]
 pub enum Instructions {
     NONE,
     PREFIX,
@@ -129,6 +130,8 @@ pub struct CPU {
     status: IME,
     pub enable_ime_next_cycle: bool,
     pub halted: bool,
+    pub halt_bug: bool,
+    pub(crate) branch_taken: bool,
 }
 
 impl CPU {
@@ -139,6 +142,8 @@ impl CPU {
             status: IME::Disabled,
             enable_ime_next_cycle: false,
             halted: false,
+            halt_bug: false,
+            branch_taken: false,
         }
     }
 
@@ -153,18 +158,62 @@ impl CPU {
         };
     }
 
-    pub fn step(&mut self, mmu: &mut MMU) {
+    pub fn set_post_boot_state(&mut self) {
+        self.registers.set_register_16(Registers16::AF, 0x01B0);
+        self.registers.set_register_16(Registers16::BC, 0x0013);
+        self.registers.set_register_16(Registers16::DE, 0x00D8);
+        self.registers.set_register_16(Registers16::HL, 0x014D);
+        self.registers.set_stack_pointer(0xFFFE);
+        self.registers.set_program_counter(0x0100);
+        self.set_status(IME::Disabled);
+        self.enable_ime_next_cycle = false;
+        self.halted = false;
+        self.halt_bug = false;
+    }
+
+    pub fn step(&mut self, mmu: &mut MMU) -> Cycles {
+        if let Some(cycles) = self.handle_interrupts(mmu) {
+            return cycles;
+        }
+
+        if self.halted {
+            if mmu.has_pending_interrupt() {
+                self.halted = false;
+            } else {
+                return 4;
+            }
+        }
+
+        let enable_ime: bool = self.enable_ime_next_cycle;
+
         let address: Address = self.registers.get_program_counter() as Address;
         let opcode: Byte = mmu.read_memory(address);
         let instruction: Instructions = CPU::get_instruction(opcode);
-        self.registers.increase_program_counter(1);
-        self.execute(mmu, instruction);
+
+        // The HALT bug makes the CPU read the byte after HALT twice
+        if self.halt_bug {
+            self.halt_bug = false;
+        } else {
+            self.registers.increase_program_counter(1);
+        }
+
+        let cycles: Cycles = self.execute(mmu, instruction);
+
+        // EI only takes effect after the instruction that follows it
+        if enable_ime && self.enable_ime_next_cycle {
+            self.enable_ime_next_cycle = false;
+            self.set_status(IME::Enabled);
+        }
+
+        cycles
     }
 
-    pub fn execute(&mut self, mmu: &mut MMU, instruction: Instructions) {
+    pub fn execute(&mut self, mmu: &mut MMU, instruction: Instructions) -> Cycles {
+        self.branch_taken = false;
+
         match instruction {
             Instructions::NONE => {}
-            Instructions::PREFIX => self.PREFIX(mmu),
+            Instructions::PREFIX => return self.PREFIX(mmu),
             Instructions::LD_r8_r8(destination, source) => self.LD_r8_r8(destination, source),
             Instructions::LD_r8_n8(destination) => self.LD_r8_n8(mmu, destination),
             Instructions::LD_r16_r16(destination, source) => self.LD_r16_r16(destination, source),
@@ -269,17 +318,158 @@ impl CPU {
             Instructions::PUSH_r16(source) => self.PUSH_r16(mmu, source),
             Instructions::DI => self.DI(),
             Instructions::EI => self.EI(),
-            Instructions::HALT => self.HALT(),
+            Instructions::HALT => self.HALT(mmu),
             Instructions::DAA => self.DAA(),
             Instructions::NOP => self.NOP(),
             Instructions::STOP => self.STOP(),
         }
+
+        CPU::get_cycles(instruction, self.branch_taken)
+    }
+
+    pub fn get_cycles(instruction: Instructions, branch_taken: bool) -> Cycles {
+        match instruction {
+            Instructions::NONE => 4,
+            Instructions::PREFIX => 4,
+            Instructions::LD_r8_r8(_, _) => 4,
+            Instructions::LD_r8_n8(_) => 8,
+            Instructions::LD_r16_r16(_, _) => 8,
+            Instructions::LD_r16_n16(_) => 12,
+            Instructions::LD_HL_r8(_) => 8,
+            Instructions::LD_HL_n8 => 12,
+            Instructions::LD_r8_HL(_) => 8,
+            Instructions::LD_r16_A(_) => 8,
+            Instructions::LD_n16_A => 16,
+            Instructions::LDH_n16_A => 12,
+            Instructions::LDH_C_A => 8,
+            Instructions::LD_A_r16(_) => 8,
+            Instructions::LD_A_n16 => 16,
+            Instructions::LDH_A_n16 => 12,
+            Instructions::LDH_A_C => 8,
+            Instructions::LD_HLI_A => 8,
+            Instructions::LD_HLD_A => 8,
+            Instructions::LD_A_HLI => 8,
+            Instructions::LD_A_HLD => 8,
+            Instructions::ADC_A_r8(_) => 4,
+            Instructions::ADC_A_HL => 8,
+            Instructions::ADC_A_n8 => 8,
+            Instructions::ADD_A_r8(_) => 4,
+            Instructions::ADD_A_HL => 8,
+            Instructions::ADD_A_n8 => 8,
+            Instructions::CP_A_r8(_) => 4,
+            Instructions::CP_A_HL => 8,
+            Instructions::CP_A_n8 => 8,
+            Instructions::DEC_r8(_) => 4,
+            Instructions::DEC_HL => 12,
+            Instructions::INC_r8(_) => 4,
+            Instructions::INC_HL => 12,
+            Instructions::SBC_A_r8(_) => 4,
+            Instructions::SBC_A_HL => 8,
+            Instructions::SBC_A_n8 => 8,
+            Instructions::SUB_A_r8(_) => 4,
+            Instructions::SUB_A_HL => 8,
+            Instructions::SUB_A_n8 => 8,
+            Instructions::ADD_HL_r16(_) => 8,
+            Instructions::DEC_r16(_) => 8,
+            Instructions::INC_r16(_) => 8,
+            Instructions::AND_A_r8(_) => 4,
+            Instructions::AND_A_HL => 8,
+            Instructions::AND_A_n8 => 8,
+            Instructions::CPL => 4,
+            Instructions::OR_A_r8(_) => 4,
+            Instructions::OR_A_HL => 8,
+            Instructions::OR_A_n8 => 8,
+            Instructions::XOR_A_r8(_) => 4,
+            Instructions::XOR_A_HL => 8,
+            Instructions::XOR_A_n8 => 8,
+            Instructions::BIT_u3_r8(_, _) => 8,
+            Instructions::BIT_u3_HL(_) => 12,
+            Instructions::RES_u3_r8(_, _) => 8,
+            Instructions::RES_u3_HL(_) => 16,
+            Instructions::SET_u3_r8(_, _) => 8,
+            Instructions::SET_u3_HL(_) => 16,
+            Instructions::RL_r8(_) => 8,
+            Instructions::RL_HL => 16,
+            Instructions::RLA => 4,
+            Instructions::RLC_r8(_) => 8,
+            Instructions::RLC_HL => 16,
+            Instructions::RLCA => 4,
+            Instructions::RR_r8(_) => 8,
+            Instructions::RR_HL => 16,
+            Instructions::RRA => 4,
+            Instructions::RRC_r8(_) => 8,
+            Instructions::RRC_HL => 16,
+            Instructions::RRCA => 4,
+            Instructions::SLA_r8(_) => 8,
+            Instructions::SLA_HL => 16,
+            Instructions::SRA_r8(_) => 8,
+            Instructions::SRA_HL => 16,
+            Instructions::SRL_r8(_) => 8,
+            Instructions::SRL_HL => 16,
+            Instructions::SWAP_r8(_) => 8,
+            Instructions::SWAP_HL => 16,
+            Instructions::CALL_n16 => 24,
+            Instructions::CALL_cc_n16 => {
+                if branch_taken {
+                    24
+                } else {
+                    12
+                }
+            }
+            Instructions::JP_HL => 4,
+            Instructions::JP_n16 => 16,
+            Instructions::JP_cc_n16 => {
+                if branch_taken {
+                    16
+                } else {
+                    12
+                }
+            }
+            Instructions::JR_n16 => 12,
+            Instructions::JR_cc_n16 => {
+                if branch_taken {
+                    12
+                } else {
+                    8
+                }
+            }
+            Instructions::RET_cc => {
+                if branch_taken {
+                    20
+                } else {
+                    8
+                }
+            }
+            Instructions::RET => 16,
+            Instructions::RETI => 16,
+            Instructions::RST => 16,
+            Instructions::CCF => 4,
+            Instructions::SCF => 4,
+            Instructions::ADD_HL_SP => 8,
+            Instructions::ADD_SP_e8 => 16,
+            Instructions::DEC_SP => 8,
+            Instructions::INC_SP => 8,
+            Instructions::LD_SP_n16 => 12,
+            Instructions::LD_n16_SP => 20,
+            Instructions::LD_HL_SP_e8 => 12,
+            Instructions::LD_SP_HL => 8,
+            Instructions::POP_AF => 12,
+            Instructions::POP_r16(_) => 12,
+            Instructions::PUSH_AF => 16,
+            Instructions::PUSH_r16(_) => 16,
+            Instructions::DI => 4,
+            Instructions::EI => 4,
+            Instructions::HALT => 4,
+            Instructions::DAA => 4,
+            Instructions::NOP => 4,
+            Instructions::STOP => 4,
+        }
     }
 
     pub fn get_instruction(opcode: Byte) -> Instructions {
         match opcode {
             0x00 => Instructions::NOP,
-            0x01 => Instructions::LD_r8_r8(Registers8::B, Registers8::C),
+            0x01 => Instructions::LD_r16_n16(Registers16::BC),
             0x02 => Instructions::LD_r16_A(Registers16::BC),
             0x03 => Instructions::INC_r16(Registers16::BC),
             0x04 => Instructions::INC_r8(Registers8::B),
@@ -651,22 +841,22 @@ impl CPU {
             0x6D => Instructions::BIT_u3_r8(5, Registers8::L),
             0x6E => Instructions::BIT_u3_HL(5),
             0x6F => Instructions::BIT_u3_r8(5, Registers8::A),
-            0x70 => Instructions::RES_u3_r8(6, Registers8::B),
-            0x71 => Instructions::RES_u3_r8(6, Registers8::C),
-            0x72 => Instructions::RES_u3_r8(6, Registers8::D),
-            0x73 => Instructions::RES_u3_r8(6, Registers8::E),
-            0x74 => Instructions::RES_u3_r8(6, Registers8::H),
-            0x75 => Instructions::RES_u3_r8(6, Registers8::L),
-            0x76 => Instructions::RES_u3_HL(6),
-            0x77 => Instructions::RES_u3_r8(6, Registers8::A),
-            0x78 => Instructions::RES_u3_r8(7, Registers8::B),
-            0x79 => Instructions::RES_u3_r8(7, Registers8::C),
-            0x7A => Instructions::RES_u3_r8(7, Registers8::D),
-            0x7B => Instructions::RES_u3_r8(7, Registers8::E),
-            0x7C => Instructions::RES_u3_r8(7, Registers8::H),
-            0x7D => Instructions::RES_u3_r8(7, Registers8::L),
-            0x7E => Instructions::RES_u3_HL(7),
-            0x7F => Instructions::RES_u3_r8(7, Registers8::A),
+            0x70 => Instructions::BIT_u3_r8(6, Registers8::B),
+            0x71 => Instructions::BIT_u3_r8(6, Registers8::C),
+            0x72 => Instructions::BIT_u3_r8(6, Registers8::D),
+            0x73 => Instructions::BIT_u3_r8(6, Registers8::E),
+            0x74 => Instructions::BIT_u3_r8(6, Registers8::H),
+            0x75 => Instructions::BIT_u3_r8(6, Registers8::L),
+            0x76 => Instructions::BIT_u3_HL(6),
+            0x77 => Instructions::BIT_u3_r8(6, Registers8::A),
+            0x78 => Instructions::BIT_u3_r8(7, Registers8::B),
+            0x79 => Instructions::BIT_u3_r8(7, Registers8::C),
+            0x7A => Instructions::BIT_u3_r8(7, Registers8::D),
+            0x7B => Instructions::BIT_u3_r8(7, Registers8::E),
+            0x7C => Instructions::BIT_u3_r8(7, Registers8::H),
+            0x7D => Instructions::BIT_u3_r8(7, Registers8::L),
+            0x7E => Instructions::BIT_u3_HL(7),
+            0x7F => Instructions::BIT_u3_r8(7, Registers8::A),
             0x80 => Instructions::RES_u3_r8(0, Registers8::B),
             0x81 => Instructions::RES_u3_r8(0, Registers8::C),
             0x82 => Instructions::RES_u3_r8(0, Registers8::D),
@@ -798,3 +988,54 @@ impl CPU {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::types::Register16, cpu::registers::Flags};
+
+    #[test]
+    fn operands_wrap_around_the_address_space() {
+        let mut cpu: CPU = CPU::new();
+        let mut mmu: MMU = MMU::new();
+
+        // JP n16 at the end of HRAM: the low byte sits in IE, the high byte wraps to 0x0000
+        mmu.write_memory(0xFFFE, 0xC3);
+        mmu.write_memory(0xFFFF, 0x34);
+        cpu.registers.set_program_counter(0xFFFE);
+        cpu.step(&mut mmu);
+
+        let high: Register16 = mmu.read_memory(0x0000) as Register16;
+        assert_eq!(cpu.registers.get_program_counter(), (high << 8) | 0x34);
+    }
+
+    #[test]
+    fn conditional_jump_at_the_last_address() {
+        let mut cpu: CPU = CPU::new();
+        let mut mmu: MMU = MMU::new();
+
+        // JP NZ in IE re-reads its opcode at PC - 1 after PC has wrapped to 0x0000
+        mmu.write_memory(0xFFFF, 0xC2);
+        cpu.registers.set_program_counter(0xFFFF);
+        cpu.registers.set_flag(Flags::Zero, true);
+        cpu.step(&mut mmu);
+
+        assert_eq!(cpu.registers.get_program_counter(), 0x0002);
+    }
+
+    #[test]
+    fn ld_n16_sp_wraps_the_high_byte() {
+        let mut cpu: CPU = CPU::new();
+        let mut mmu: MMU = MMU::new();
+
+        for (offset, value) in [0x08, 0xFF, 0xFF].iter().enumerate() {
+            mmu.write_memory(0xFF80 + offset, *value);
+        }
+        cpu.registers.set_program_counter(0xFF80);
+        cpu.registers.set_stack_pointer(0x1234);
+        cpu.step(&mut mmu);
+
+        assert_eq!(mmu.read_memory(0xFFFF), 0x34);
+        assert_eq!(cpu.registers.get_program_counter(), 0xFF83);
+    }
+}
@@ -26,8 +26,6 @@ impl CPU {
             .set_flag(Flags::HalfCarry, (a & 0x0F) + (value & 0x0F) + carry > 0x0F);
         self.registers
             .set_flag(Flags::Carry, did_overflow1 || did_overflow2);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn ADC_A_HL(&mut self, mmu: &MMU) {
@@ -49,8 +47,6 @@ impl CPU {
             .set_flag(Flags::HalfCarry, (a & 0x0F) + (value & 0x0F) + carry > 0x0F);
         self.registers
             .set_flag(Flags::Carry, did_overflow1 || did_overflow2);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn ADC_A_n8(&mut self, mmu: &MMU) {
@@ -87,8 +83,6 @@ impl CPU {
         self.registers
             .set_flag(Flags::HalfCarry, (a & 0x0F) + (value & 0x0F) > 0x0F);
         self.registers.set_flag(Flags::Carry, did_overflow);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn ADD_A_HL(&mut self, mmu: &MMU) {
@@ -103,8 +97,6 @@ impl CPU {
         self.registers
             .set_flag(Flags::HalfCarry, (a & 0x0F) + (value & 0x0F) > 0x0F);
         self.registers.set_flag(Flags::Carry, did_overflow);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn ADD_A_n8(&mut self, mmu: &MMU) {
@@ -133,8 +125,6 @@ impl CPU {
         self.registers
             .set_flag(Flags::HalfCarry, (a & 0x0F) < (value & 0x0F));
         self.registers.set_flag(Flags::Carry, did_overflow);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn CP_A_HL(&mut self, mmu: &MMU) {
@@ -148,8 +138,6 @@ impl CPU {
         self.registers
             .set_flag(Flags::HalfCarry, (a & 0x0F) < (value & 0x0F));
         self.registers.set_flag(Flags::Carry, did_overflow);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn CP_A_n8(&mut self, mmu: &MMU) {
@@ -174,8 +162,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, true);
         self.registers
             .set_flag(Flags::HalfCarry, (value & 0x0F) == 0x00);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn DEC_HL(&mut self, mmu: &mut MMU) {
@@ -188,8 +174,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, true);
         self.registers
             .set_flag(Flags::HalfCarry, (value & 0x0F) == 0x00);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn INC_r8(&mut self, target: Registers8) {
@@ -201,8 +185,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers
             .set_flag(Flags::HalfCarry, (value & 0x0F) == 0x0F);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn INC_HL(&mut self, mmu: &mut MMU) {
@@ -215,8 +197,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers
             .set_flag(Flags::HalfCarry, (value & 0x0F) == 0x0F);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SBC_A_r8(&mut self, source: Registers8) {
@@ -237,8 +217,6 @@ impl CPU {
             .set_flag(Flags::HalfCarry, (a & 0x0F) < (value & 0x0F) + carry);
         self.registers
             .set_flag(Flags::Carry, did_overflow1 || did_overflow2);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SBC_A_HL(&mut self, mmu: &MMU) {
@@ -260,8 +238,6 @@ impl CPU {
             .set_flag(Flags::HalfCarry, (a & 0x0F) < (value & 0x0F) + carry);
         self.registers
             .set_flag(Flags::Carry, did_overflow1 || did_overflow2);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SBC_A_n8(&mut self, mmu: &MMU) {
@@ -298,8 +274,6 @@ impl CPU {
         self.registers
             .set_flag(Flags::HalfCarry, (a & 0x0F) < (value & 0x0F));
         self.registers.set_flag(Flags::Carry, did_overflow);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SUB_A_HL(&mut self, mmu: &MMU) {
@@ -314,8 +288,6 @@ impl CPU {
         self.registers
             .set_flag(Flags::HalfCarry, (a & 0x0F) < (value & 0x0F));
         self.registers.set_flag(Flags::Carry, did_overflow);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SUB_A_n8(&mut self, mmu: &MMU) {
@@ -343,21 +315,17 @@ impl CPU {
         self.registers
             .set_flag(Flags::HalfCarry, (hl & 0x0FFF) + (value & 0x0FFF) > 0x0FFF);
         self.registers.set_flag(Flags::Carry, did_overflow);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn DEC_r16(&mut self, target: Registers16) {
         let value: Register16 = self.registers.get_register_16(target);
         let (result, did_overflow) = value.overflowing_sub(1);
         self.registers.set_register_16(target, result);
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn INC_r16(&mut self, target: Registers16) {
         let value: Register16 = self.registers.get_register_16(target);
         let (result, did_overflow) = value.overflowing_add(1);
         self.registers.set_register_16(target, result);
-        self.registers.set_program_counter(1);
     }
 }
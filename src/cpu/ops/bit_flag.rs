@@ -15,8 +15,6 @@ impl CPU {
         self.registers.set_flag(Flags::Zero, !bit_value);
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, true);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn BIT_u3_HL(&mut self, mmu: &MMU, bit: u3) {
@@ -27,15 +25,12 @@ impl CPU {
         self.registers.set_flag(Flags::Zero, !bit_value);
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, true);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RES_u3_r8(&mut self, bit: u3, target: Registers8) {
         let value: Register8 = self.registers.get_register_8(target);
         let result: Register8 = value & !(1 << bit);
         self.registers.set_register_8(target, result);
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RES_u3_HL(&mut self, mmu: &mut MMU, bit: u3) {
@@ -43,14 +38,12 @@ impl CPU {
         let value: Register8 = mmu.read_memory(address);
         let result: Register8 = value & !(1 << bit);
         mmu.write_memory(address, result);
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SET_u3_r8(&mut self, bit: u3, target: Registers8) {
         let value: Register8 = self.registers.get_register_8(target);
         let result: Register8 = value | (1 << bit);
         self.registers.set_register_8(target, result);
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SET_u3_HL(&mut self, mmu: &mut MMU, bit: u3) {
@@ -58,6 +51,5 @@ impl CPU {
         let value: Register8 = mmu.read_memory(address);
         let result: Register8 = value | (1 << bit);
         mmu.write_memory(address, result);
-        self.registers.set_program_counter(1);
     }
 }
@@ -22,8 +22,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RL_HL(&mut self, mmu: &mut MMU) {
@@ -41,8 +39,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RLA(&mut self) {
@@ -59,8 +55,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (a & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RLC_r8(&mut self, target: Registers8) {
@@ -72,8 +66,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RLC_HL(&mut self, mmu: &mut MMU) {
@@ -86,8 +78,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RLCA(&mut self) {
@@ -99,8 +89,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (a & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RR_r8(&mut self, target: Registers8) {
@@ -117,8 +105,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RR_HL(&mut self, mmu: &mut MMU) {
@@ -136,8 +122,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RRA(&mut self) {
@@ -154,8 +138,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (a & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RRC_r8(&mut self, target: Registers8) {
@@ -167,8 +149,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RRC_HL(&mut self, mmu: &mut MMU) {
@@ -181,8 +161,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn RRCA(&mut self) {
@@ -194,8 +172,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (a & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SLA_r8(&mut self, target: Registers8) {
@@ -207,8 +183,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SLA_HL(&mut self, mmu: &mut MMU) {
@@ -221,8 +195,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x80) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SRA_r8(&mut self, target: Registers8) {
@@ -235,8 +207,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SRA_HL(&mut self, mmu: &mut MMU) {
@@ -250,8 +220,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SRL_r8(&mut self, target: Registers8) {
@@ -263,8 +231,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SRL_HL(&mut self, mmu: &mut MMU) {
@@ -277,8 +243,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, (value & 0x01) != 0);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SWAP_r8(&mut self, target: Registers8) {
@@ -290,8 +254,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SWAP_HL(&mut self, mmu: &mut MMU) {
@@ -304,7 +266,5 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 }
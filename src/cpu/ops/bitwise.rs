@@ -18,8 +18,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, true);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn AND_A_HL(&mut self, mmu: &MMU) {
@@ -33,8 +31,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, true);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn AND_A_n8(&mut self, mmu: &MMU) {
@@ -59,8 +55,6 @@ impl CPU {
 
         self.registers.set_flag(Flags::Subtraction, true);
         self.registers.set_flag(Flags::HalfCarry, true);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn OR_A_r8(&mut self, source: Registers8) {
@@ -73,8 +67,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn OR_A_HL(&mut self, mmu: &MMU) {
@@ -88,8 +80,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn OR_A_n8(&mut self, mmu: &MMU) {
@@ -117,8 +107,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn XOR_A_HL(&mut self, mmu: &MMU) {
@@ -132,8 +120,6 @@ impl CPU {
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, false);
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn XOR_A_n8(&mut self, mmu: &MMU) {
@@ -6,13 +6,11 @@ impl CPU {
         self.registers.set_flag(Flags::Carry, !carry);
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn SCF(&mut self) {
         self.registers.set_flag(Flags::Carry, true);
         self.registers.set_flag(Flags::Subtraction, false);
         self.registers.set_flag(Flags::HalfCarry, false);
-        self.registers.set_program_counter(1);
     }
 }
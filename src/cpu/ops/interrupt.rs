@@ -1,18 +1,54 @@
-use crate::cpu::cpu::{CPU, IME};
+use crate::{
+    common::types::{Address, Byte, Cycles, Register16},
+    cpu::{
+        cpu::{CPU, IME},
+        registers::Registers16,
+    },
+    interrupt::interrupt::Interrupt,
+    mmu::mmu::MMU,
+};
 
 impl CPU {
     pub(crate) fn DI(&mut self) {
         self.set_status(IME::Disabled);
-        self.registers.set_program_counter(1);
+        self.enable_ime_next_cycle = false;
     }
 
     pub(crate) fn EI(&mut self) {
         self.enable_ime_next_cycle = true;
-        self.registers.set_program_counter(1);
     }
 
-    pub(crate) fn HALT(&mut self) {
-        self.halted = true;
-        self.registers.set_program_counter(1);
+    pub(crate) fn HALT(&mut self, mmu: &MMU) {
+        let ime_disabled: bool = matches!(self.get_status(), IME::Disabled);
+        if ime_disabled && mmu.has_pending_interrupt() {
+            self.halt_bug = true;
+        } else {
+            self.halted = true;
+        }
+    }
+
+    pub(crate) fn handle_interrupts(&mut self, mmu: &mut MMU) -> Option<Cycles> {
+        if matches!(self.get_status(), IME::Disabled) {
+            return None;
+        }
+
+        let interrupt: Interrupt = mmu.get_interrupt().get_pending()?;
+        mmu.get_interrupt().acknowledge(interrupt);
+        self.set_status(IME::Disabled);
+        self.halted = false;
+
+        let pc: Register16 = self.registers.get_program_counter();
+        let sp: Address = self.registers.get_register_16(Registers16::SP) as Address;
+        let sp: Address = (sp as Register16).wrapping_sub(1) as Address;
+        mmu.write_memory(sp, (pc >> 8) as Byte);
+        let sp: Address = (sp as Register16).wrapping_sub(1) as Address;
+        mmu.write_memory(sp, (pc & 0x00FF) as Byte);
+        self.registers
+            .set_register_16(Registers16::SP, sp as Register16);
+
+        self.registers
+            .set_program_counter(interrupt.get_vector() as Register16);
+
+        Some(20)
     }
 }
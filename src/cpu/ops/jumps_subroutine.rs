@@ -11,14 +11,17 @@ impl CPU {
     pub(crate) fn CALL_n16(&mut self, mmu: &mut MMU) {
         let address_pc: Address = self.registers.get_program_counter() as Address;
         let low: Byte = mmu.read_memory(address_pc);
-        let high: Byte = mmu.read_memory(address_pc + 1);
+        let high: Byte = mmu.read_memory((address_pc as Register16).wrapping_add(1) as Address);
         let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
 
         let sp: Address = self.registers.get_register_16(Registers16::SP) as Address;
-        let pc: Register16 = self.registers.get_program_counter() + 2 as Register16;
-        let sp = sp.wrapping_sub(2);
+        let pc: Register16 = self.registers.get_program_counter().wrapping_add(2);
+        let sp = (sp as Register16).wrapping_sub(2) as Address;
         mmu.write_memory(sp as Address, (pc & 0x00FF) as Byte);
-        mmu.write_memory((sp + 1) as Address, (pc >> 8) as Byte);
+        mmu.write_memory(
+            (sp as Register16).wrapping_add(1) as Address,
+            (pc >> 8) as Byte,
+        );
         self.registers
             .set_register_16(Registers16::SP, sp as Register16);
 
@@ -26,10 +29,11 @@ impl CPU {
     }
 
     pub(crate) fn CALL_cc_n16(&mut self, mmu: &mut MMU) {
-        let opcode: Byte = mmu.read_memory(self.registers.get_program_counter() as Address - 2);
+        let opcode: Byte =
+            mmu.read_memory(self.registers.get_program_counter().wrapping_sub(1) as Address);
         let address_pc: Address = self.registers.get_program_counter() as Address;
         let low: Byte = mmu.read_memory(address_pc);
-        let high: Byte = mmu.read_memory(address_pc + 1);
+        let high: Byte = mmu.read_memory((address_pc as Register16).wrapping_add(1) as Address);
         let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
 
         let condition: bool = match opcode {
@@ -40,12 +44,17 @@ impl CPU {
             _ => panic!("Invalid opcode for CALL_cc_n16"),
         };
 
+        self.branch_taken = condition;
+
         if condition {
             let sp: Address = self.registers.get_register_16(Registers16::SP) as Address;
-            let pc: Register16 = self.registers.get_program_counter() + 2 as Register16;
-            let sp = sp.wrapping_sub(2);
+            let pc: Register16 = self.registers.get_program_counter().wrapping_add(2);
+            let sp = (sp as Register16).wrapping_sub(2) as Address;
             mmu.write_memory(sp as Address, (pc & 0x00FF) as Byte);
-            mmu.write_memory((sp + 1) as Address, (pc >> 8) as Byte);
+            mmu.write_memory(
+                (sp as Register16).wrapping_add(1) as Address,
+                (pc >> 8) as Byte,
+            );
             self.registers
                 .set_register_16(Registers16::SP, sp as Register16);
 
@@ -53,8 +62,6 @@ impl CPU {
         } else {
             self.registers.increase_program_counter(2);
         }
-
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn JP_HL(&mut self) {
@@ -65,16 +72,17 @@ impl CPU {
     pub(crate) fn JP_n16(&mut self, mmu: &MMU) {
         let address_pc: Address = self.registers.get_program_counter() as Address;
         let low: Byte = mmu.read_memory(address_pc);
-        let high: Byte = mmu.read_memory(address_pc + 1);
+        let high: Byte = mmu.read_memory((address_pc as Register16).wrapping_add(1) as Address);
         let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
         self.registers.set_program_counter(address as Register16);
     }
 
     pub(crate) fn JP_cc_n16(&mut self, mmu: &MMU) {
-        let opcode: Byte = mmu.read_memory(self.registers.get_program_counter() as Address - 2);
+        let opcode: Byte =
+            mmu.read_memory(self.registers.get_program_counter().wrapping_sub(1) as Address);
         let address_pc: Address = self.registers.get_program_counter() as Address;
         let low: Byte = mmu.read_memory(address_pc);
-        let high: Byte = mmu.read_memory(address_pc + 1);
+        let high: Byte = mmu.read_memory((address_pc as Register16).wrapping_add(1) as Address);
         let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
 
         let condition: bool = match opcode {
@@ -85,6 +93,8 @@ impl CPU {
             _ => panic!("Invalid opcode for JP_cc_n16"),
         };
 
+        self.branch_taken = condition;
+
         if condition {
             self.registers.set_program_counter(address as Register16);
         } else {
@@ -101,7 +111,8 @@ impl CPU {
     }
 
     pub(crate) fn JR_cc_n16(&mut self, mmu: &MMU) {
-        let opcode: Byte = mmu.read_memory(self.registers.get_program_counter() as Address - 2);
+        let opcode: Byte =
+            mmu.read_memory(self.registers.get_program_counter().wrapping_sub(1) as Address);
         let address_pc: Address = self.registers.get_program_counter() as Address;
         let offset: i8 = mmu.read_memory(address_pc) as i8;
         let pc: Register16 = self.registers.get_program_counter();
@@ -114,6 +125,8 @@ impl CPU {
             _ => panic!("Invalid opcode for JR_cc_n16"),
         };
 
+        self.branch_taken = condition;
+
         if condition {
             let address: Address = (pc as i32 + offset as i32 + 1) as Address;
             self.registers.set_program_counter(address as Register16);
@@ -123,7 +136,8 @@ impl CPU {
     }
 
     pub(crate) fn RET_cc(&mut self, mmu: &mut MMU) {
-        let opcode: Byte = mmu.read_memory(self.registers.get_program_counter() as Address - 2);
+        let opcode: Byte =
+            mmu.read_memory(self.registers.get_program_counter().wrapping_sub(1) as Address);
         let condition: bool = match opcode {
             0xC0 => self.registers.is_flag_down(Flags::Zero),
             0xC8 => self.registers.is_flag_up(Flags::Zero),
@@ -131,23 +145,23 @@ impl CPU {
             0xD8 => self.registers.is_flag_up(Flags::Carry),
             _ => panic!("Invalid opcode for RET_cc"),
         };
+        self.branch_taken = condition;
+
         if condition {
             let sp: Address = self.registers.get_register_16(Registers16::SP) as Address;
             let low: Byte = mmu.read_memory(sp);
-            let high: Byte = mmu.read_memory(sp + 1);
+            let high: Byte = mmu.read_memory((sp as Register16).wrapping_add(1) as Address);
             let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
             self.registers
                 .set_register_16(Registers16::SP, sp.wrapping_add(2) as Register16);
             self.registers.set_program_counter(address as Register16);
-        } else {
-            self.registers.increase_program_counter(1);
         }
     }
 
     pub(crate) fn RET(&mut self, mmu: &mut MMU) {
         let sp: Address = self.registers.get_register_16(Registers16::SP) as Address;
         let low: Byte = mmu.read_memory(sp);
-        let high: Byte = mmu.read_memory(sp + 1);
+        let high: Byte = mmu.read_memory((sp as Register16).wrapping_add(1) as Address);
         let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
         self.registers
             .set_register_16(Registers16::SP, sp.wrapping_add(2) as Register16);
@@ -157,7 +171,7 @@ impl CPU {
     pub(crate) fn RETI(&mut self, mmu: &mut MMU) {
         let sp: Address = self.registers.get_register_16(Registers16::SP) as Address;
         let low: Byte = mmu.read_memory(sp);
-        let high: Byte = mmu.read_memory(sp + 1);
+        let high: Byte = mmu.read_memory((sp as Register16).wrapping_add(1) as Address);
         let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
         self.registers
             .set_register_16(Registers16::SP, sp.wrapping_add(2) as Register16);
@@ -166,7 +180,8 @@ impl CPU {
     }
 
     pub(crate) fn RST(&mut self, mmu: &mut MMU) {
-        let opcode: Byte = mmu.read_memory(self.registers.get_program_counter() as Address - 1);
+        let opcode: Byte =
+            mmu.read_memory(self.registers.get_program_counter().wrapping_sub(1) as Address);
         let n: Byte = match opcode {
             0xC7 => 0x00,
             0xCF => 0x08,
@@ -182,9 +197,9 @@ impl CPU {
         let pc: Register16 = self.registers.get_program_counter();
         let sp: Address = self.registers.get_register_16(Registers16::SP) as Address;
 
-        let sp: Address = sp.wrapping_sub(1);
+        let sp: Address = (sp as Register16).wrapping_sub(1) as Address;
         mmu.write_memory(sp, (pc >> 8) as Byte);
-        let sp: Address = sp.wrapping_sub(1);
+        let sp: Address = (sp as Register16).wrapping_sub(1) as Address;
         mmu.write_memory(sp, (pc & 0x00FF) as Byte);
         self.registers
             .set_register_16(Registers16::SP, sp as Register16);
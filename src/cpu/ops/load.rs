@@ -11,7 +11,6 @@ impl CPU {
     pub(crate) fn LD_r8_r8(&mut self, destination: Registers8, source: Registers8) {
         let value: Register8 = self.registers.get_register_8(source);
         self.registers.set_register_8(destination, value);
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn LD_r8_n8(&mut self, mmu: &mut MMU, destination: Registers8) {
@@ -23,12 +22,13 @@ impl CPU {
     pub(crate) fn LD_r16_r16(&mut self, destination: Registers16, source: Registers16) {
         let value: Register16 = self.registers.get_register_16(source);
         self.registers.set_register_16(destination, value);
-        self.registers.set_program_counter(1);
     }
 
     pub(crate) fn LD_r16_n16(&mut self, mmu: &mut MMU, destination: Registers16) {
-        let value: Register16 =
-            mmu.read_memory(self.registers.get_program_counter() as Address) as Register16;
+        let address_pc: Address = self.registers.get_program_counter() as Address;
+        let low: Byte = mmu.read_memory(address_pc);
+        let high: Byte = mmu.read_memory((address_pc as Register16).wrapping_add(1) as Address);
+        let value: Register16 = ((high as Register16) << 8) | (low as Register16);
         self.registers.set_register_16(destination, value);
         self.registers.increase_program_counter(2);
     }
@@ -61,7 +61,9 @@ impl CPU {
 
     pub(crate) fn LD_n16_A(&mut self, mmu: &mut MMU) {
         let address_pc: Address = self.registers.get_program_counter() as Address;
-        let address: Address = mmu.read_memory(address_pc) as Address;
+        let low: Byte = mmu.read_memory(address_pc);
+        let high: Byte = mmu.read_memory((address_pc as Register16).wrapping_add(1) as Address);
+        let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
         let value: Register8 = self.registers.get_register_8(Registers8::A);
         mmu.write_memory(address, value);
         self.registers.increase_program_counter(2);
@@ -91,7 +93,9 @@ impl CPU {
 
     pub(crate) fn LD_A_n16(&mut self, mmu: &MMU) {
         let address_pc: Address = self.registers.get_program_counter() as Address;
-        let address: Address = mmu.read_memory(address_pc) as Address;
+        let low: Byte = mmu.read_memory(address_pc);
+        let high: Byte = mmu.read_memory((address_pc as Register16).wrapping_add(1) as Address);
+        let address: Address = (((high as u16) << 8) | (low as u16)) as Address;
         let value: Register8 = mmu.read_memory(address);
         self.registers.set_register_8(Registers8::A, value);
         self.registers.increase_program_counter(2);
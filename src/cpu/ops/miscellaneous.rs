@@ -1,5 +1,5 @@
 use crate::{
-    common::types::{Address, Byte, Register8},
+    common::types::{Address, Byte, Cycles, Register8},
     cpu::{
         cpu::{CPU, Instructions},
         registers::{Flags, Registers8},
@@ -35,23 +35,19 @@ impl CPU {
         self.registers.set_flag(Flags::Zero, a == 0);
         self.registers.set_flag(Flags::HalfCarry, false);
         self.registers.set_flag(Flags::Carry, carry);
-
-        self.registers.set_program_counter(1);
     }
 
-    pub(crate) fn NOP(&mut self) {
-        self.registers.set_program_counter(1);
-    }
+    pub(crate) fn NOP(&mut self) {}
 
     pub(crate) fn STOP(&mut self) {
         self.halted = true;
-        self.registers.set_program_counter(1);
+        self.registers.increase_program_counter(1);
     }
 
-    pub(crate) fn PREFIX(&mut self, mmu: &mut MMU) {
+    pub(crate) fn PREFIX(&mut self, mmu: &mut MMU) -> Cycles {
         let opcode: Byte = mmu.read_memory(self.registers.get_program_counter() as Address);
         let instructions: Instructions = CPU::get_instruction_prefixed(opcode);
         self.registers.increase_program_counter(1);
-        self.execute(mmu, instructions);
+        self.execute(mmu, instructions)
     }
 }
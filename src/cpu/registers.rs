@@ -139,7 +139,7 @@ impl Registers {
     }
 
     pub fn set_program_counter(&mut self, value: Register16) {
-        self.sp = value;
+        self.pc = value;
     }
 
     pub fn get_program_counter(&self) -> Register16 {
@@ -165,10 +165,10 @@ impl Registers {
 
     pub fn flag_down(&mut self, flag: Flags) {
         match flag {
-            Flags::Zero => self.set_register_8(Registers8::F, self.f & (1 << 7)),
-            Flags::Subtraction => self.set_register_8(Registers8::F, self.f & (1 << 6)),
-            Flags::HalfCarry => self.set_register_8(Registers8::F, self.f & (1 << 5)),
-            Flags::Carry => self.set_register_8(Registers8::F, self.f & (1 << 4)),
+            Flags::Zero => self.set_register_8(Registers8::F, self.f & !(1 << 7)),
+            Flags::Subtraction => self.set_register_8(Registers8::F, self.f & !(1 << 6)),
+            Flags::HalfCarry => self.set_register_8(Registers8::F, self.f & !(1 << 5)),
+            Flags::Carry => self.set_register_8(Registers8::F, self.f & !(1 << 4)),
         }
     }
 
@@ -1,40 +1,63 @@
 use crate::{
-    cartridge::cartridge::Cartridge,
-    mmu::{boot_rom::BootROM, mmu::MMU},
+    apu::{
+        apu::{APU, CHANNEL_COUNT},
+        envelope::Envelope,
+    },
+    audio::output::AudioOutput,
+    cartridge::{cartridge::Cartridge, error::CartridgeError},
+    common::constant::{CPU_FREQUENCY, CYCLES_PER_FRAME, MAX_FRAME_LAG},
+    gameboy::gameboy::GameBoy,
+    joypad::{joypad::Button, keymap::KeyMap},
+    mmu::mmu::MMU,
+    options::Options,
     ppu::lcd::LCD,
 };
 use {
     cairo::{Context, ImageSurface},
     gtk::{
-        Application, ApplicationWindow, Box, DrawingArea, FileChooserAction, FileChooserDialog,
-        FileFilter, ResponseType,
+        Application, ApplicationWindow, Box, ButtonsType, CheckButton, DrawingArea,
+        EventControllerKey, FileChooserAction, FileChooserDialog, FileFilter, Label, MessageDialog,
+        MessageType, ResponseType,
         ResponseType::{Accept, Cancel},
         Window, cairo,
         gio::{ActionEntry, Menu, MenuItem},
         glib,
-        glib::{ControlFlow::Continue, object::ObjectExt},
+        glib::{ControlFlow::Continue, Propagation, object::ObjectExt},
         prelude::{
-            ActionMapExtManual, ApplicationExt, ApplicationExtManual, BoxExt, Cast, DialogExt,
-            DrawingAreaExtManual, FileChooserExt, FileExt, GtkApplicationExt, GtkWindowExt,
-            WidgetExt,
+            ActionMapExtManual, ApplicationExt, ApplicationExtManual, BoxExt, Cast, CheckButtonExt,
+            DialogExt, DrawingAreaExtManual, FileChooserExt, FileChooserExtManual, FileExt,
+            GtkApplicationExt, GtkWindowExt, WidgetExt,
         },
     },
     std::{
+        path::PathBuf,
         sync::{Arc, Mutex, MutexGuard},
         time::{Duration, Instant},
     },
 };
 
+const RECORD_CHANNELS_CHOICE: &str = "channels";
+const CHANNEL_NAMES: [&str; CHANNEL_COUNT] = [
+    "Canal 1 - Carré avec balayage",
+    "Canal 2 - Carré",
+    "Canal 3 - Onde",
+    "Canal 4 - Bruit",
+];
+const DUTY_LABELS: [&str; 4] = ["12,5 %", "25 %", "50 %", "75 %"];
+const WAVE_VOLUME_LABELS: [&str; 4] = ["muet", "100 %", "50 %", "25 %"];
+
 pub struct Emulator {
     app: Application,
+    options: Options,
 }
 
 impl Emulator {
-    pub fn new() -> Self {
+    pub fn new(options: Options) -> Self {
         Emulator {
             app: Application::builder()
                 .application_id("org.gtk_rs.EmulatorWindow")
                 .build(),
+            options,
         }
     }
 
@@ -49,7 +72,18 @@ impl Emulator {
         let filter: FileFilter = FileFilter::new();
         filter.set_name(Some("ROM Game Boy"));
         filter.add_pattern("*.gb");
+        filter.add_pattern("*.gbc");
+        filter.add_pattern("*.zip");
+        filter.add_pattern("*.gz");
+        filter.add_pattern("*.7z");
+
+        let patch_filter: FileFilter = FileFilter::new();
+        patch_filter.set_name(Some("Patch IPS / UPS / BPS"));
+        patch_filter.add_pattern("*.ips");
+        patch_filter.add_pattern("*.ups");
+        patch_filter.add_pattern("*.bps");
         dialog.add_filter(&filter);
+        dialog.add_filter(&patch_filter);
 
         dialog
     }
@@ -61,16 +95,21 @@ impl Emulator {
                     if let Some(path_str) = path.to_str() {
                         println!("ROM sélectionnée : {}", path_str);
                         if let Some(window) = dialog.transient_for() {
-                            if let Some(mmu_ptr) = unsafe { window.data::<Arc<Mutex<MMU>>>("mmu") }
+                            if let Some(gameboy_ptr) =
+                                unsafe { window.data::<Arc<Mutex<GameBoy>>>("gameboy") }
                             {
-                                let mmu_ref: &Arc<Mutex<MMU>> = unsafe { mmu_ptr.as_ref() };
-                                if let Ok(mut mmu) = mmu_ref.lock() {
-                                    let cartridge: Cartridge = Cartridge::insert(path_str);
-                                    let title: String =
-                                        format!("Game Boy - {}", cartridge.get_title());
-                                    window.set_title(Some(&title));
-                                    mmu.set_cartridge(cartridge);
-                                    mmu.boot_init_sequence();
+                                let gameboy_ref: &Arc<Mutex<GameBoy>> =
+                                    unsafe { gameboy_ptr.as_ref() };
+                                match Cartridge::insert(path_str) {
+                                    Ok(cartridge) => {
+                                        if let Ok(mut gameboy) = gameboy_ref.lock() {
+                                            let title: String =
+                                                format!("Game Boy - {}", cartridge.get_title());
+                                            window.set_title(Some(&title));
+                                            gameboy.insert_cartridge(cartridge);
+                                        }
+                                    }
+                                    Err(error) => Self::show_cartridge_error(&window, &error),
                                 }
                             }
                         }
@@ -81,6 +120,107 @@ impl Emulator {
         dialog.close();
     }
 
+    fn show_cartridge_error(window: &Window, error: &CartridgeError) {
+        Self::show_error(
+            Some(window),
+            "Impossible de charger la ROM",
+            &error.to_string(),
+        );
+    }
+
+    fn show_error(window: Option<&Window>, text: &str, details: &str) {
+        let dialog: MessageDialog = MessageDialog::builder()
+            .modal(true)
+            .message_type(MessageType::Error)
+            .buttons(ButtonsType::Close)
+            .text(text)
+            .secondary_text(details)
+            .build();
+        dialog.set_transient_for(window);
+
+        dialog.connect_response(|dialog, _| dialog.close());
+        dialog.present();
+    }
+
+    fn setup_record_chooser(window: Option<&Window>) -> FileChooserDialog {
+        let dialog: FileChooserDialog = FileChooserDialog::new(
+            Some("Enregistrer l'audio"),
+            window,
+            FileChooserAction::Save,
+            &[("Annuler", Cancel), ("Enregistrer", Accept)],
+        );
+        dialog.set_current_name("audio.wav");
+        dialog.add_choice(RECORD_CHANNELS_CHOICE, "Un fichier par canal", &[]);
+
+        let filter: FileFilter = FileFilter::new();
+        filter.set_name(Some("Audio WAV"));
+        filter.add_pattern("*.wav");
+        dialog.add_filter(&filter);
+
+        dialog
+    }
+
+    fn handle_record_response(
+        dialog: &FileChooserDialog,
+        response: ResponseType,
+        gameboy: &Arc<Mutex<GameBoy>>,
+    ) {
+        if response == Accept {
+            let path: Option<PathBuf> = dialog.file().and_then(|file| file.path());
+            let per_channel: bool =
+                dialog.choice(RECORD_CHANNELS_CHOICE).as_deref() == Some("true");
+
+            if let (Some(path), Ok(mut gameboy)) = (path, gameboy.lock()) {
+                if let Err(error) = gameboy.start_audio_recording(&path, per_channel) {
+                    Self::show_error(
+                        dialog.transient_for().as_ref(),
+                        "Impossible de démarrer l'enregistrement",
+                        &error.to_string(),
+                    );
+                }
+            }
+        }
+        dialog.close();
+    }
+
+    fn setup_vgm_chooser(window: Option<&Window>) -> FileChooserDialog {
+        let dialog: FileChooserDialog = FileChooserDialog::new(
+            Some("Exporter la musique"),
+            window,
+            FileChooserAction::Save,
+            &[("Annuler", Cancel), ("Exporter", Accept)],
+        );
+        dialog.set_current_name("musique.vgm");
+
+        let filter: FileFilter = FileFilter::new();
+        filter.set_name(Some("Musique VGM"));
+        filter.add_pattern("*.vgm");
+        dialog.add_filter(&filter);
+
+        dialog
+    }
+
+    fn handle_vgm_response(
+        dialog: &FileChooserDialog,
+        response: ResponseType,
+        gameboy: &Arc<Mutex<GameBoy>>,
+    ) {
+        if response == Accept {
+            let path: Option<PathBuf> = dialog.file().and_then(|file| file.path());
+
+            if let (Some(path), Ok(mut gameboy)) = (path, gameboy.lock()) {
+                if let Err(error) = gameboy.start_vgm_logging(&path) {
+                    Self::show_error(
+                        dialog.transient_for().as_ref(),
+                        "Impossible de démarrer l'export VGM",
+                        &error.to_string(),
+                    );
+                }
+            }
+        }
+        dialog.close();
+    }
+
     fn draw_lcd_area(cr: &Context, lcd: &mut LCD, mmu: &mut MMU) {
         const MARGIN: f64 = 80.0;
 
@@ -103,7 +243,18 @@ impl Emulator {
         let pos_x: f64 = ((window_width - scaled_width) / 2.0).max(MARGIN);
         let pos_y: f64 = ((window_height - scaled_height) / 2.0).max(MARGIN);
 
-        cr.translate(pos_x, pos_y);
+        // Shake the screen while the cartridge rumble motor is on
+        let shake: f64 = if mmu.get_cartridge().is_rumbling() {
+            if lcd.get_frame_count() % 2 == 0 {
+                4.0
+            } else {
+                -4.0
+            }
+        } else {
+            0.0
+        };
+
+        cr.translate(pos_x + shake, pos_y);
         cr.scale(scale, scale);
 
         cr.set_source_surface(&surface, 0.0, 0.0)
@@ -137,16 +288,26 @@ impl Emulator {
     }
 
     pub fn start(&self) {
+        // The menu actions and the main window drive the same emulator
+        let gameboy: Arc<Mutex<GameBoy>> = Arc::new(Mutex::new(GameBoy::new()));
+
         let app_clone = self.app.clone();
+        let gameboy_clone_for_menu: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
         self.app.connect_startup(move |_| {
-            setup_menu(&app_clone);
+            setup_menu(&app_clone, &gameboy_clone_for_menu);
         });
 
-        self.app.connect_activate(Self::create_main_window);
-        self.app.run();
+        let options: Options = self.options.clone();
+        self.app.connect_activate(move |app| {
+            Self::create_main_window(app, &options, Arc::clone(&gameboy))
+        });
+
+        // Our own flags were already parsed, so GTK only gets the program name
+        let program: Vec<String> = std::env::args().take(1).collect();
+        self.app.run_with_args(&program);
     }
 
-    fn create_main_window(app: &Application) {
+    fn create_main_window(app: &Application, options: &Options, gameboy: Arc<Mutex<GameBoy>>) {
         let window: ApplicationWindow = ApplicationWindow::builder()
             .application(app)
             .title("Game Boy")
@@ -158,27 +319,65 @@ impl Emulator {
         let lcd_area: DrawingArea = DrawingArea::builder().hexpand(true).vexpand(true).build();
 
         let mut lcd: LCD = LCD::new();
-        let mmu: Arc<Mutex<MMU>> = Arc::new(Mutex::new(MMU::new()));
-        let mmu_clone_for_draw: Arc<Mutex<MMU>> = Arc::clone(&mmu);
+        let audio_output: AudioOutput = AudioOutput::open();
+        let sample_rate: u32 = audio_output.get_sample_rate();
+        // Shared with the shutdown handler so a WAV sink gets its sizes written
+        let audio_output: Arc<Mutex<AudioOutput>> = Arc::new(Mutex::new(audio_output));
+        if let Ok(mut gameboy) = gameboy.lock() {
+            gameboy.set_sample_rate(sample_rate);
+            if let Some(path) = &options.record_audio {
+                if let Err(error) = gameboy.start_audio_recording(path, options.record_channels) {
+                    eprintln!(
+                        "Impossible de démarrer l'enregistrement {} : {}",
+                        path.display(),
+                        error
+                    );
+                }
+            }
+            if let Some(path) = &options.record_vgm {
+                if let Err(error) = gameboy.start_vgm_logging(path) {
+                    eprintln!(
+                        "Impossible de démarrer l'export VGM {} : {}",
+                        path.display(),
+                        error
+                    );
+                }
+            }
+        }
+        let gameboy_clone_for_draw: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
 
         lcd_area.set_draw_func(move |_, cr, _, _| {
-            if let Ok(mut mmu) = mmu_clone_for_draw.lock() {
-                Self::draw_lcd_area(cr, &mut lcd, &mut mmu);
+            if let Ok(mut gameboy) = gameboy_clone_for_draw.lock() {
+                Self::draw_lcd_area(cr, &mut lcd, gameboy.get_mmu());
             }
         });
 
         window.set_child(Some(&lcd_area));
 
         unsafe {
-            window.set_data("mmu", mmu.clone());
+            window.set_data("gameboy", gameboy.clone());
         }
 
-        Self::create_debug_window(app, mmu.clone());
+        Self::setup_key_controller(&window, gameboy.clone());
+
+        let gameboy_clone_for_shutdown: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
+        let audio_output_clone_for_shutdown: Arc<Mutex<AudioOutput>> = Arc::clone(&audio_output);
+        app.connect_shutdown(move |_| {
+            if let Ok(mut gameboy) = gameboy_clone_for_shutdown.lock() {
+                gameboy.flush_save();
+                gameboy.stop_audio_recording();
+                gameboy.stop_vgm_logging();
+            }
+            if let Ok(mut audio_output) = audio_output_clone_for_shutdown.lock() {
+                audio_output.finish();
+            }
+        });
+        Self::create_debug_window(app, gameboy.clone());
 
-        let frame_duration: Duration = Duration::from_secs_f64(1.0 / 60.0);
+        let frame_duration: Duration = Self::get_frame_duration();
         let last_frame: Arc<Mutex<Instant>> = Arc::new(Mutex::new(Instant::now()));
         let last_frame_clone: Arc<Mutex<Instant>> = Arc::clone(&last_frame);
-        let mmu_clone_for_tick: Arc<Mutex<MMU>> = Arc::clone(&mmu);
+        let gameboy_clone_for_tick: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
 
         let tick = move || {
             let current_time: Instant = Instant::now();
@@ -187,12 +386,16 @@ impl Emulator {
             let elapsed: Duration = current_time.duration_since(*last_frame);
 
             if elapsed >= frame_duration {
-                if let Ok(mut mmu) = mmu_clone_for_tick.lock() {
-                    mmu.on_frame();
+                if let (Ok(mut gameboy), Ok(mut audio_output)) =
+                    (gameboy_clone_for_tick.lock(), audio_output.lock())
+                {
+                    gameboy.run_frame();
+                    let samples: Vec<f32> = gameboy.take_audio_samples();
+                    audio_output.push(&samples);
                 }
 
                 lcd_area.queue_draw();
-                *last_frame = current_time;
+                Self::advance_frame_deadline(&mut last_frame, current_time, frame_duration);
             }
 
             Continue
@@ -203,11 +406,60 @@ impl Emulator {
         window.present();
     }
 
-    fn create_debug_window(app: &Application, mmu: Arc<Mutex<MMU>>) {
+    /// A DMG frame lasts 70224 cycles, which is slightly longer than 1/60 s.
+    fn get_frame_duration() -> Duration {
+        Duration::from_secs_f64(CYCLES_PER_FRAME as f64 / CPU_FREQUENCY as f64)
+    }
+
+    fn advance_frame_deadline(
+        last_frame: &mut Instant,
+        current_time: Instant,
+        frame_duration: Duration,
+    ) {
+        // Carrying the overshoot over keeps the average rate on the hardware's 59.73 Hz
+        *last_frame += frame_duration;
+        // After a long stall, resynchronise instead of rushing through the missed frames
+        if current_time.duration_since(*last_frame) > frame_duration * MAX_FRAME_LAG {
+            *last_frame = current_time;
+        }
+    }
+
+    fn setup_key_controller(window: &ApplicationWindow, gameboy: Arc<Mutex<GameBoy>>) {
+        let keymap: Arc<KeyMap> = Arc::new(KeyMap::load("keymap.cfg"));
+        let keymap_clone: Arc<KeyMap> = Arc::clone(&keymap);
+        let gameboy_clone: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
+
+        let controller: EventControllerKey = EventControllerKey::new();
+
+        controller.connect_key_pressed(move |_, key, _, _| {
+            let button: Option<Button> = key.name().and_then(|name| keymap.get_button(&name));
+            if let Some(button) = button {
+                if let Ok(mut gameboy) = gameboy.lock() {
+                    gameboy.press_button(button);
+                }
+                Propagation::Stop
+            } else {
+                Propagation::Proceed
+            }
+        });
+
+        controller.connect_key_released(move |_, key, _, _| {
+            let button: Option<Button> = key.name().and_then(|name| keymap_clone.get_button(&name));
+            if let Some(button) = button {
+                if let Ok(mut gameboy) = gameboy_clone.lock() {
+                    gameboy.release_button(button);
+                }
+            }
+        });
+
+        window.add_controller(controller);
+    }
+
+    fn create_debug_window(app: &Application, gameboy: Arc<Mutex<GameBoy>>) {
         let debug_window = ApplicationWindow::builder()
             .application(app)
             .title("Game Boy - Debug")
-            .default_width(1024)
+            .default_width(1536)
             .default_height(512)
             .build();
 
@@ -219,11 +471,12 @@ impl Emulator {
 
         let mut lcd_tile_map: LCD = LCD::new();
         let mut lcd_tile_set: LCD = LCD::new();
-        let mmu_clone1: Arc<Mutex<MMU>> = Arc::clone(&mmu);
-        let mmu_clone2: Arc<Mutex<MMU>> = Arc::clone(&mmu);
+        let gameboy_clone1: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
+        let gameboy_clone2: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
+        let gameboy_clone3: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
 
         tilemap_area.set_draw_func(move |_, cr, width, height| {
-            if let Ok(mut mmu) = mmu_clone1.lock() {
+            if let Ok(mut gameboy) = gameboy_clone1.lock() {
                 let scale: f64 = f64::min(width as f64 / 256.0, height as f64 / 256.0);
                 let x: f64 = (width as f64 - (256.0 * scale)) / 2.0;
                 let y: f64 = (height as f64 - (256.0 * scale)) / 2.0;
@@ -231,13 +484,13 @@ impl Emulator {
                 cr.translate(x, y);
                 cr.scale(scale, scale);
 
-                Self::draw_debug_area(cr, &mut lcd_tile_map, &mut mmu);
+                Self::draw_debug_area(cr, &mut lcd_tile_map, gameboy.get_mmu());
             }
         });
 
         tileset_area.set_draw_func(move |_, cr, width, height| {
-            if let Ok(mut mmu) = mmu_clone2.lock() {
-                let ppu = mmu.get_ppu();
+            if let Ok(mut gameboy) = gameboy_clone2.lock() {
+                let ppu = gameboy.get_mmu().get_ppu();
                 let surface: ImageSurface = lcd_tile_set.render_tile_set(ppu);
                 let scale: f64 = f64::min(width as f64 / 256.0, height as f64 / 256.0);
 
@@ -251,12 +504,20 @@ impl Emulator {
             }
         });
 
-        hbox.append(&tilemap_area);
+        let tilemap_box: Box = Box::new(gtk::Orientation::Vertical, 5);
+        let tilemap_label: Label = Label::new(None);
+        let dma_label: Label = Label::new(None);
+        tilemap_box.append(&tilemap_area);
+        tilemap_box.append(&tilemap_label);
+        tilemap_box.append(&dma_label);
+
+        hbox.append(&tilemap_box);
         hbox.append(&tileset_area);
+        hbox.append(&Self::create_sound_panel(gameboy.clone()));
 
         debug_window.set_child(Some(&hbox));
 
-        let frame_duration: Duration = Duration::from_secs_f64(1.0 / 60.0);
+        let frame_duration: Duration = Self::get_frame_duration();
         let last_frame: Arc<Mutex<Instant>> = Arc::new(Mutex::new(Instant::now()));
         let last_frame_clone: Arc<Mutex<Instant>> = Arc::clone(&last_frame);
 
@@ -266,9 +527,29 @@ impl Emulator {
             let elapsed: Duration = current_time.duration_since(*last_frame);
 
             if elapsed >= frame_duration {
+                if let Ok(mut gameboy) = gameboy_clone3.lock() {
+                    let ppu = gameboy.get_mmu().get_ppu();
+                    tilemap_label.set_text(&format!(
+                        "Adressage : {} - Carte : 0x{:04X}",
+                        ppu.get_addressing_method().get_label(),
+                        ppu.get_bg_tile_map()
+                    ));
+
+                    let dma = gameboy.get_mmu().get_dma();
+                    if dma.is_active() {
+                        dma_label.set_text(&format!(
+                            "DMA OAM : actif (0x{:02X}00, {}/160)",
+                            dma.read_register(),
+                            dma.get_progress()
+                        ));
+                    } else {
+                        dma_label.set_text("DMA OAM : inactif");
+                    }
+                }
+
                 tilemap_area.queue_draw();
                 tileset_area.queue_draw();
-                *last_frame = current_time;
+                Self::advance_frame_deadline(&mut last_frame, current_time, frame_duration);
             }
 
             Continue
@@ -278,9 +559,189 @@ impl Emulator {
 
         debug_window.present();
     }
+
+    fn create_sound_panel(gameboy: Arc<Mutex<GameBoy>>) -> Box {
+        let panel: Box = Box::new(gtk::Orientation::Vertical, 5);
+        let mut scopes: Vec<DrawingArea> = Vec::new();
+        let mut register_labels: Vec<Label> = Vec::new();
+
+        for (channel, name) in CHANNEL_NAMES.iter().enumerate() {
+            let header: Box = Box::new(gtk::Orientation::Horizontal, 5);
+            let title: Label = Label::new(Some(name));
+            title.set_hexpand(true);
+            title.set_xalign(0.0);
+
+            let mute_button: CheckButton = CheckButton::with_label("Muet");
+            let gameboy_clone_for_mute: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
+            mute_button.connect_toggled(move |button| {
+                if let Ok(mut gameboy) = gameboy_clone_for_mute.lock() {
+                    let apu: &mut APU = gameboy.get_mmu().get_apu();
+                    apu.set_channel_muted(channel, button.is_active());
+                }
+            });
+
+            let solo_button: CheckButton = CheckButton::with_label("Solo");
+            let gameboy_clone_for_solo: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
+            solo_button.connect_toggled(move |button| {
+                if let Ok(mut gameboy) = gameboy_clone_for_solo.lock() {
+                    let apu: &mut APU = gameboy.get_mmu().get_apu();
+                    apu.set_channel_soloed(channel, button.is_active());
+                }
+            });
+
+            header.append(&title);
+            header.append(&mute_button);
+            header.append(&solo_button);
+
+            let scope: DrawingArea = DrawingArea::builder().hexpand(true).vexpand(true).build();
+            let gameboy_clone_for_scope: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy);
+            scope.set_draw_func(move |_, cr, width, height| {
+                if let Ok(gameboy) = gameboy_clone_for_scope.lock() {
+                    let samples: &[f32] = &gameboy.get_channel_samples()[channel];
+                    Self::draw_oscilloscope(cr, width as f64, height as f64, samples);
+                }
+            });
+
+            let registers_label: Label = Label::new(None);
+            registers_label.set_xalign(0.0);
+            registers_label.set_wrap(true);
+
+            panel.append(&header);
+            panel.append(&scope);
+            panel.append(&registers_label);
+            scopes.push(scope);
+            register_labels.push(registers_label);
+        }
+
+        let frame_duration: Duration = Self::get_frame_duration();
+        let mut last_frame: Instant = Instant::now();
+
+        let tick = move || {
+            let current_time: Instant = Instant::now();
+            if current_time.duration_since(last_frame) >= frame_duration {
+                if let Ok(mut gameboy) = gameboy.lock() {
+                    let apu: &mut APU = gameboy.get_mmu().get_apu();
+                    for (channel, label) in register_labels.iter().enumerate() {
+                        label.set_text(&Self::format_channel_registers(apu, channel));
+                    }
+                }
+
+                for scope in &scopes {
+                    scope.queue_draw();
+                }
+                Self::advance_frame_deadline(&mut last_frame, current_time, frame_duration);
+            }
+
+            Continue
+        };
+
+        glib::timeout_add_local(Duration::from_millis(1), tick);
+
+        panel
+    }
+
+    fn draw_oscilloscope(cr: &Context, width: f64, height: f64, samples: &[f32]) {
+        cr.set_source_rgb(0.1, 0.1, 0.1);
+        cr.paint().expect("Impossible de dessiner l'oscilloscope");
+
+        cr.set_line_width(1.0);
+        cr.set_source_rgb(0.3, 0.3, 0.3);
+        cr.move_to(0.0, height / 2.0);
+        cr.line_to(width, height / 2.0);
+        cr.stroke().expect("Impossible de dessiner l'oscilloscope");
+
+        if samples.len() < 4 {
+            return;
+        }
+
+        // Start on a rising edge so periodic waveforms stay still from one frame to the next
+        let window: usize = samples.len() / 2;
+        let start: usize = (1..samples.len() - window)
+            .find(|&index| samples[index - 1] < 0.0 && samples[index] >= 0.0)
+            .unwrap_or(0);
+
+        cr.set_source_rgb(0.4, 0.9, 0.4);
+        for (index, sample) in samples[start..start + window].iter().enumerate() {
+            let x: f64 = index as f64 * width / (window - 1) as f64;
+            let y: f64 = height / 2.0 - sample.clamp(-1.0, 1.0) as f64 * height * 0.45;
+            if index == 0 {
+                cr.move_to(x, y);
+            } else {
+                cr.line_to(x, y);
+            }
+        }
+        cr.stroke().expect("Impossible de dessiner l'oscilloscope");
+    }
+
+    fn format_channel_registers(apu: &APU, channel: usize) -> String {
+        match channel {
+            0 | 1 => {
+                let square = if channel == 0 {
+                    apu.get_square1()
+                } else {
+                    apu.get_square2()
+                };
+                let mut text: String = format!(
+                    "{} - Fréquence : 0x{:03X} ({:.1} Hz) - Rapport cyclique : {} - Volume : {}/15 - {}",
+                    Self::format_channel_state(square.is_enabled()),
+                    square.get_frequency(),
+                    square.get_frequency_hz(),
+                    DUTY_LABELS[square.get_duty() as usize],
+                    square.get_volume(),
+                    Self::format_envelope(square.get_envelope())
+                );
+                if channel == 0 {
+                    text += &format!(" - Balayage : 0x{:02X}", square.get_sweep());
+                }
+                text
+            }
+            2 => {
+                let wave = apu.get_wave();
+                format!(
+                    "{} - Fréquence : 0x{:03X} ({:.1} Hz) - Volume : {}",
+                    Self::format_channel_state(wave.is_enabled()),
+                    wave.get_frequency(),
+                    wave.get_frequency_hz(),
+                    WAVE_VOLUME_LABELS[wave.get_volume_code() as usize]
+                )
+            }
+            _ => {
+                let noise = apu.get_noise();
+                format!(
+                    "{} - Horloge : {:.1} Hz - Mode : {} - Volume : {}/15 - {}",
+                    Self::format_channel_state(noise.is_enabled()),
+                    noise.get_frequency_hz(),
+                    if noise.is_short_mode() {
+                        "7 bits"
+                    } else {
+                        "15 bits"
+                    },
+                    noise.get_volume(),
+                    Self::format_envelope(noise.get_envelope())
+                )
+            }
+        }
+    }
+
+    fn format_channel_state(enabled: bool) -> &'static str {
+        if enabled { "Actif" } else { "Inactif" }
+    }
+
+    fn format_envelope(envelope: &Envelope) -> String {
+        format!(
+            "Enveloppe : {} {} (période {})",
+            envelope.get_initial_volume(),
+            if envelope.is_increasing() {
+                "↑"
+            } else {
+                "↓"
+            },
+            envelope.get_period()
+        )
+    }
 }
 
-fn setup_menu(app: &Application) {
+fn setup_menu(app: &Application, gameboy: &Arc<Mutex<GameBoy>>) {
     let open: ActionEntry<Application> = ActionEntry::builder("open")
         .activate(|app: &Application, _, _| {
             let window: Window = app.active_window().unwrap();
@@ -297,19 +758,87 @@ fn setup_menu(app: &Application) {
         })
         .build();
 
+    let gameboy_clone_for_record: Arc<Mutex<GameBoy>> = Arc::clone(gameboy);
+    let record_audio: ActionEntry<Application> = ActionEntry::builder("record_audio")
+        .activate(move |app: &Application, _, _| {
+            let window: Option<Window> = app.active_window();
+            let dialog: FileChooserDialog = Emulator::setup_record_chooser(window.as_ref());
+
+            let gameboy: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy_clone_for_record);
+            dialog.connect_response(move |dialog, response| {
+                Emulator::handle_record_response(dialog, response, &gameboy);
+            });
+
+            dialog.show();
+        })
+        .build();
+
+    let gameboy_clone_for_stop_recording: Arc<Mutex<GameBoy>> = Arc::clone(gameboy);
+    let stop_recording: ActionEntry<Application> = ActionEntry::builder("stop_recording")
+        .activate(move |_: &Application, _, _| {
+            if let Ok(mut gameboy) = gameboy_clone_for_stop_recording.lock() {
+                gameboy.stop_audio_recording();
+            }
+        })
+        .build();
+
+    let gameboy_clone_for_vgm: Arc<Mutex<GameBoy>> = Arc::clone(gameboy);
+    let record_vgm: ActionEntry<Application> = ActionEntry::builder("record_vgm")
+        .activate(move |app: &Application, _, _| {
+            let window: Option<Window> = app.active_window();
+            let dialog: FileChooserDialog = Emulator::setup_vgm_chooser(window.as_ref());
+
+            let gameboy: Arc<Mutex<GameBoy>> = Arc::clone(&gameboy_clone_for_vgm);
+            dialog.connect_response(move |dialog, response| {
+                Emulator::handle_vgm_response(dialog, response, &gameboy);
+            });
+
+            dialog.show();
+        })
+        .build();
+
+    let gameboy_clone_for_stop_vgm: Arc<Mutex<GameBoy>> = Arc::clone(gameboy);
+    let stop_vgm: ActionEntry<Application> = ActionEntry::builder("stop_vgm")
+        .activate(move |_: &Application, _, _| {
+            if let Ok(mut gameboy) = gameboy_clone_for_stop_vgm.lock() {
+                gameboy.stop_vgm_logging();
+            }
+        })
+        .build();
+
     let quit: ActionEntry<Application> = ActionEntry::builder("quit")
         .activate(|app: &Application, _, _| app.quit())
         .build();
 
-    app.add_action_entries([open, quit]);
+    app.add_action_entries([
+        open,
+        record_audio,
+        stop_recording,
+        record_vgm,
+        stop_vgm,
+        quit,
+    ]);
 
     let menu_bar: Menu = Menu::new();
     let file_menu: Menu = Menu::new();
 
     let open_item: MenuItem = MenuItem::new(Some("Ouvrir..."), Some("app.open"));
+    let record_item: MenuItem =
+        MenuItem::new(Some("Enregistrer l'audio..."), Some("app.record_audio"));
+    let stop_record_item: MenuItem = MenuItem::new(
+        Some("Arrêter l'enregistrement audio"),
+        Some("app.stop_recording"),
+    );
+    let vgm_item: MenuItem =
+        MenuItem::new(Some("Exporter la musique (VGM)..."), Some("app.record_vgm"));
+    let stop_vgm_item: MenuItem = MenuItem::new(Some("Arrêter l'export VGM"), Some("app.stop_vgm"));
     let quit_item: MenuItem = MenuItem::new(Some("Quitter"), Some("app.quit"));
 
     file_menu.append_item(&open_item);
+    file_menu.append_item(&record_item);
+    file_menu.append_item(&stop_record_item);
+    file_menu.append_item(&vgm_item);
+    file_menu.append_item(&stop_vgm_item);
     file_menu.append_item(&quit_item);
 
     menu_bar.append_submenu(Some("Fichier"), &file_menu);
@@ -0,0 +1,149 @@
+use crate::{
+    apu::apu::CHANNEL_COUNT,
+    audio::recorder::AudioRecorder,
+    cartridge::cartridge::Cartridge,
+    common::{
+        constant::{CYCLES_PER_FRAME, SAVE_FLUSH_FRAMES},
+        types::Cycles,
+    },
+    cpu::cpu::CPU,
+    joypad::joypad::Button,
+    mmu::{boot_rom::BootROM, mmu::MMU},
+};
+use std::{io, path::Path};
+
+pub struct GameBoy {
+    cpu: CPU,
+    mmu: MMU,
+    is_booted: bool,
+    frame_cycles: Cycles,
+    frames_since_flush: u32,
+    recorder: Option<AudioRecorder>,
+    channel_samples: [Vec<f32>; CHANNEL_COUNT],
+}
+
+impl GameBoy {
+    pub fn new() -> Self {
+        GameBoy {
+            cpu: CPU::new(),
+            mmu: MMU::new(),
+            is_booted: false,
+            frame_cycles: 0,
+            frames_since_flush: 0,
+            recorder: None,
+            channel_samples: Default::default(),
+        }
+    }
+
+    pub fn get_cpu(&mut self) -> &mut CPU {
+        &mut self.cpu
+    }
+
+    pub fn get_mmu(&mut self) -> &mut MMU {
+        &mut self.mmu
+    }
+
+    pub fn press_button(&mut self, button: Button) {
+        self.mmu.press_button(button);
+    }
+
+    pub fn release_button(&mut self, button: Button) {
+        self.mmu.release_button(button);
+    }
+
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.mmu.get_apu().set_sample_rate(sample_rate);
+    }
+
+    pub fn take_audio_samples(&mut self) -> Vec<f32> {
+        let samples: Vec<f32> = self.mmu.get_apu().take_samples();
+        let channels: [Vec<f32>; CHANNEL_COUNT] = self.mmu.get_apu().take_channel_samples();
+
+        if let Some(recorder) = &mut self.recorder {
+            if let Err(error) = recorder.write(&samples, &channels) {
+                eprintln!(
+                    "Impossible d'écrire l'enregistrement {} : {}",
+                    recorder.get_path().display(),
+                    error
+                );
+                self.stop_audio_recording();
+            }
+        }
+        self.channel_samples = channels;
+        samples
+    }
+
+    /// Returns the per-channel output of the last frame, for the oscilloscope.
+    pub fn get_channel_samples(&self) -> &[Vec<f32>; CHANNEL_COUNT] {
+        &self.channel_samples
+    }
+
+    pub fn start_audio_recording(&mut self, path: &Path, per_channel: bool) -> io::Result<()> {
+        self.stop_audio_recording();
+        let sample_rate: u32 = self.mmu.get_apu().get_sample_rate();
+        self.recorder = Some(AudioRecorder::create(path, sample_rate, per_channel)?);
+        Ok(())
+    }
+
+    pub fn stop_audio_recording(&mut self) {
+        if let Some(mut recorder) = self.recorder.take() {
+            if let Err(error) = recorder.finish() {
+                eprintln!(
+                    "Impossible de terminer l'enregistrement {} : {}",
+                    recorder.get_path().display(),
+                    error
+                );
+            }
+        }
+    }
+
+    pub fn start_vgm_logging(&mut self, path: &Path) -> io::Result<()> {
+        self.mmu.get_apu().start_vgm_logging(path)
+    }
+
+    pub fn stop_vgm_logging(&mut self) {
+        self.mmu.get_apu().stop_vgm_logging();
+    }
+
+    pub fn insert_cartridge(&mut self, cartridge: Cartridge) {
+        self.mmu.set_cartridge(cartridge);
+        self.mmu.boot_init_sequence();
+        self.cpu = CPU::new();
+        self.is_booted = false;
+        self.frame_cycles = 0;
+        self.frames_since_flush = 0;
+    }
+
+    pub fn flush_save(&mut self) {
+        let cartridge: &mut Cartridge = self.mmu.get_cartridge();
+        let force: bool = cartridge.has_rtc();
+        cartridge.flush_save(force);
+        self.frames_since_flush = 0;
+    }
+
+    pub fn run_frame(&mut self) {
+        if !self.is_booted {
+            if self.mmu.boot_update_animation() {
+                self.mmu.boot_finish_sequence();
+                self.cpu.set_post_boot_state();
+                self.is_booted = true;
+            }
+            self.mmu.tick(CYCLES_PER_FRAME);
+            return;
+        }
+
+        while self.frame_cycles < CYCLES_PER_FRAME {
+            let cycles: Cycles = self.cpu.step(&mut self.mmu);
+            self.mmu.tick(cycles);
+            self.frame_cycles += cycles;
+        }
+
+        self.frame_cycles -= CYCLES_PER_FRAME;
+
+        self.frames_since_flush += 1;
+        if self.frames_since_flush >= SAVE_FLUSH_FRAMES {
+            self.mmu.get_cartridge().flush_save(false);
+            self.frames_since_flush = 0;
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::common::{
+    constant::DEFAULT_BYTE,
+    types::{Address, Byte},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interrupt {
+    VBlank,
+    LCD,
+    Timer,
+    Serial,
+    Joypad,
+}
+
+pub const INTERRUPT_PRIORITY: [Interrupt; 5] = [
+    Interrupt::VBlank,
+    Interrupt::LCD,
+    Interrupt::Timer,
+    Interrupt::Serial,
+    Interrupt::Joypad,
+];
+
+impl Interrupt {
+    pub fn get_mask(&self) -> Byte {
+        match self {
+            Interrupt::VBlank => 1 << 0,
+            Interrupt::LCD => 1 << 1,
+            Interrupt::Timer => 1 << 2,
+            Interrupt::Serial => 1 << 3,
+            Interrupt::Joypad => 1 << 4,
+        }
+    }
+
+    pub fn get_vector(&self) -> Address {
+        match self {
+            Interrupt::VBlank => 0x0040,
+            Interrupt::LCD => 0x0048,
+            Interrupt::Timer => 0x0050,
+            Interrupt::Serial => 0x0058,
+            Interrupt::Joypad => 0x0060,
+        }
+    }
+}
+
+pub struct InterruptController {
+    interrupt_flag: Byte,
+    interrupt_enable: Byte,
+}
+
+impl InterruptController {
+    pub fn new() -> Self {
+        InterruptController {
+            interrupt_flag: DEFAULT_BYTE,
+            interrupt_enable: DEFAULT_BYTE,
+        }
+    }
+
+    pub fn read_flag(&self) -> Byte {
+        // Bits 5-7 are not wired and always read as 1
+        self.interrupt_flag | 0xE0
+    }
+
+    pub fn write_flag(&mut self, value: Byte) {
+        self.interrupt_flag = value & 0x1F;
+    }
+
+    pub fn read_enable(&self) -> Byte {
+        self.interrupt_enable
+    }
+
+    pub fn write_enable(&mut self, value: Byte) {
+        self.interrupt_enable = value;
+    }
+
+    pub fn request(&mut self, interrupt: Interrupt) {
+        self.interrupt_flag |= interrupt.get_mask();
+    }
+
+    pub fn acknowledge(&mut self, interrupt: Interrupt) {
+        self.interrupt_flag &= !interrupt.get_mask();
+    }
+
+    pub fn has_pending(&self) -> bool {
+        (self.interrupt_flag & self.interrupt_enable & 0x1F) != 0
+    }
+
+    pub fn get_pending(&self) -> Option<Interrupt> {
+        let pending: Byte = self.interrupt_flag & self.interrupt_enable;
+        INTERRUPT_PRIORITY
+            .iter()
+            .copied()
+            .find(|interrupt| pending & interrupt.get_mask() != 0)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    common::types::Byte,
+    interrupt::interrupt::{Interrupt, InterruptController},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Button {
+    Right,
+    Left,
+    Up,
+    Down,
+    A,
+    B,
+    Select,
+    Start,
+}
+
+impl Button {
+    pub fn get_mask(&self) -> Byte {
+        match self {
+            Button::Right | Button::A => 1 << 0,
+            Button::Left | Button::B => 1 << 1,
+            Button::Up | Button::Select => 1 << 2,
+            Button::Down | Button::Start => 1 << 3,
+        }
+    }
+
+    pub fn is_direction(&self) -> bool {
+        matches!(
+            self,
+            Button::Right | Button::Left | Button::Up | Button::Down
+        )
+    }
+
+    pub fn from_name(name: &str) -> Option<Button> {
+        match name.to_lowercase().as_str() {
+            "right" => Some(Button::Right),
+            "left" => Some(Button::Left),
+            "up" => Some(Button::Up),
+            "down" => Some(Button::Down),
+            "a" => Some(Button::A),
+            "b" => Some(Button::B),
+            "select" => Some(Button::Select),
+            "start" => Some(Button::Start),
+            _ => None,
+        }
+    }
+}
+
+pub struct Joypad {
+    select: Byte,
+    directions: Byte,
+    actions: Byte,
+}
+
+impl Joypad {
+    pub fn new() -> Self {
+        Joypad {
+            select: 0x30,
+            directions: 0,
+            actions: 0,
+        }
+    }
+
+    pub fn read_register(&self) -> Byte {
+        0xC0 | self.select | self.get_lines()
+    }
+
+    pub fn write_register(&mut self, value: Byte, interrupt: &mut InterruptController) {
+        let previous: Byte = self.get_lines();
+        self.select = value & 0x30;
+        self.update_interrupt(previous, interrupt);
+    }
+
+    pub fn press(&mut self, button: Button, interrupt: &mut InterruptController) {
+        let previous: Byte = self.get_lines();
+        if button.is_direction() {
+            self.directions |= button.get_mask();
+        } else {
+            self.actions |= button.get_mask();
+        }
+        self.update_interrupt(previous, interrupt);
+    }
+
+    pub fn release(&mut self, button: Button) {
+        if button.is_direction() {
+            self.directions &= !button.get_mask();
+        } else {
+            self.actions &= !button.get_mask();
+        }
+    }
+
+    fn get_lines(&self) -> Byte {
+        // Select lines and button lines are active low
+        let mut pressed: Byte = 0;
+        if (self.select & (1 << 4)) == 0 {
+            pressed |= self.directions;
+        }
+        if (self.select & (1 << 5)) == 0 {
+            pressed |= self.actions;
+        }
+        !pressed & 0x0F
+    }
+
+    fn update_interrupt(&self, previous: Byte, interrupt: &mut InterruptController) {
+        // The interrupt fires when any input line goes from high to low
+        if (previous & !self.get_lines()) != 0 {
+            interrupt.request(Interrupt::Joypad);
+        }
+    }
+}
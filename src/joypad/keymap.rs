@@ -0,0 +1,62 @@
+use crate::joypad::joypad::Button;
+use std::{collections::HashMap, fs};
+
+pub struct KeyMap {
+    bindings: HashMap<String, Button>,
+}
+
+impl KeyMap {
+    pub fn new() -> Self {
+        let mut keymap: KeyMap = KeyMap {
+            bindings: HashMap::new(),
+        };
+
+        keymap.bind("Right", Button::Right);
+        keymap.bind("Left", Button::Left);
+        keymap.bind("Up", Button::Up);
+        keymap.bind("Down", Button::Down);
+        keymap.bind("x", Button::A);
+        keymap.bind("z", Button::B);
+        keymap.bind("BackSpace", Button::Select);
+        keymap.bind("Return", Button::Start);
+        keymap
+    }
+
+    /// Loads the default mapping then applies `button = key` overrides from a file.
+    pub fn load(path: &str) -> KeyMap {
+        let mut keymap: KeyMap = KeyMap::new();
+        let content: String = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(_) => return keymap,
+        };
+
+        for line in content.lines() {
+            let line: &str = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some((button, key)) = line.split_once('=') {
+                match Button::from_name(button.trim()) {
+                    Some(button) => keymap.rebind(key.trim(), button),
+                    None => eprintln!("Bouton inconnu dans {} : {}", path, button.trim()),
+                }
+            }
+        }
+
+        keymap
+    }
+
+    pub fn bind(&mut self, key: &str, button: Button) {
+        self.bindings.insert(key.to_lowercase(), button);
+    }
+
+    pub fn rebind(&mut self, key: &str, button: Button) {
+        self.bindings.retain(|_, bound| *bound != button);
+        self.bind(key, button);
+    }
+
+    pub fn get_button(&self, key: &str) -> Option<Button> {
+        self.bindings.get(&key.to_lowercase()).copied()
+    }
+}
@@ -0,0 +1,2 @@
+pub mod joypad;
+pub mod keymap;
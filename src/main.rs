@@ -1,12 +1,28 @@
+mod apu;
+mod audio;
 mod cartridge;
 pub mod common;
 mod cpu;
 mod emulator;
+mod gameboy;
+mod interrupt;
+mod joypad;
 mod mmu;
+mod options;
 mod ppu;
-use crate::emulator::Emulator;
+mod timer;
+use crate::{emulator::Emulator, options::Options};
+use std::{env, process};
 
 fn main() {
-    let emulator: Emulator = Emulator::new();
+    let options: Options = match Options::parse(env::args()) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, Options::get_usage());
+            process::exit(2);
+        }
+    };
+
+    let emulator: Emulator = Emulator::new(options);
     emulator.start();
 }
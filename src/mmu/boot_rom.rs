@@ -1,5 +1,8 @@
 use super::mmu::MMU;
-use crate::common::{address::TILE_MAP, types::Byte};
+use crate::common::{
+    address::{BGP, LCDC, NR11, NR12, NR50, NR51, NR52, TILE_MAP},
+    types::Byte,
+};
 use std::sync::{Mutex, MutexGuard, OnceLock};
 
 pub struct BootAnimation {
@@ -48,11 +51,11 @@ impl BootAnimation {
 pub trait BootROM {
     fn boot_init_sequence(&mut self);
     fn boot_update_animation(&mut self) -> bool;
+    fn boot_finish_sequence(&mut self);
 }
 
 impl BootROM for MMU {
     fn boot_init_sequence(&mut self) {
-        self.get_cartridge().print_data();
         self.get_ppu().reset_vram();
 
         for idx in TILE_MAP.start..TILE_MAP.end {
@@ -63,10 +66,13 @@ impl BootROM for MMU {
         self.get_ppu().print_logo(logo_nintendo);
 
         self.get_ppu().set_screen_scroll(0, 80);
+        self.write_memory(LCDC.offset, 0x91);
+        self.write_memory(BGP.offset, 0xFC);
 
         let animation_mutex: &Mutex<BootAnimation> =
             BOOT_ANIMATION.get_or_init(|| Mutex::new(BootAnimation::new()));
         let mut animation: MutexGuard<'_, BootAnimation> = animation_mutex.lock().unwrap();
+        *animation = BootAnimation::new();
         animation.start();
     }
 
@@ -90,4 +96,16 @@ impl BootROM for MMU {
             false
         }
     }
+
+    fn boot_finish_sequence(&mut self) {
+        self.get_ppu().set_screen_scroll(0, 0);
+        self.write_memory(BGP.offset, 0xFC);
+
+        // The boot ROM leaves the APU powered with channel 1 set up for its chime
+        self.write_memory(NR52.offset, 0x80);
+        self.write_memory(NR11.offset, 0x80);
+        self.write_memory(NR12.offset, 0xF3);
+        self.write_memory(NR50.offset, 0x77);
+        self.write_memory(NR51.offset, 0xF3);
+    }
 }
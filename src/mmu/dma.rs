@@ -0,0 +1,68 @@
+use crate::common::{
+    address::OAM,
+    constant::DEFAULT_BYTE,
+    types::{Address, Byte, Cycles},
+};
+
+pub struct OamDMA {
+    register: Byte,
+    active: bool,
+    index: Address,
+    cycles: Cycles,
+}
+
+impl OamDMA {
+    pub fn new() -> Self {
+        OamDMA {
+            register: DEFAULT_BYTE,
+            active: false,
+            index: 0,
+            cycles: 0,
+        }
+    }
+
+    pub fn read_register(&self) -> Byte {
+        self.register
+    }
+
+    pub fn start(&mut self, value: Byte) {
+        // Writing while a transfer is running restarts it from the new source
+        self.register = value;
+        self.active = true;
+        self.index = 0;
+        self.cycles = 0;
+    }
+
+    pub fn tick(&mut self, cycles: Cycles) {
+        if self.active {
+            self.cycles += cycles;
+        }
+    }
+
+    pub fn next_transfer(&mut self) -> Option<(Address, Address)> {
+        if !self.active || self.cycles < 4 {
+            return None;
+        }
+
+        // One byte is copied per M-cycle
+        self.cycles -= 4;
+        let source: Address = ((self.register as Address) << 8) + self.index;
+        let destination: Address = OAM.start + self.index;
+
+        self.index += 1;
+        if self.index == OAM.size {
+            self.active = false;
+            self.cycles = 0;
+        }
+
+        Some((source, destination))
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn get_progress(&self) -> Address {
+        self.index
+    }
+}
@@ -1,23 +1,32 @@
 use crate::{
+    apu::apu::APU,
     cartridge::cartridge::Cartridge,
     common::{
         address::{
-            ECHO, HRAM, IE_REGISTER, INVALID_OAM, IO, NINTENDO_LOGO, OAM, RAM, ROM, VRAM, WRAM,
+            AUDIO, BGP, DIV, DMA, ECHO, HRAM, IE_REGISTER, IF, INVALID_OAM, IO, LCDC, LY, LYC,
+            NINTENDO_LOGO, OAM, OBP0, OBP1, P1, RAM, ROM, SCX, SCY, STAT, TAC, TIMA, TMA, VRAM,
+            WRAM, WX, WY,
         },
         constant::DEFAULT_BYTE,
-        types::{Address, Byte},
+        types::{Address, Byte, Cycles},
     },
-    // cpu::cpu::CPU,
-    mmu::boot_rom::BootROM,
+    interrupt::interrupt::{Interrupt, InterruptController},
+    joypad::joypad::{Button, Joypad},
+    mmu::dma::OamDMA,
     ppu::ppu::PPU,
+    timer::timer::Timer,
 };
 use std::sync::{Mutex, MutexGuard, OnceLock};
 
 pub static FRAME_COUNTER: OnceLock<Mutex<Byte>> = OnceLock::new();
 
 pub struct MMU {
-    // cpu: CPU,
     ppu: PPU,
+    apu: APU,
+    interrupt: InterruptController,
+    timer: Timer,
+    dma: OamDMA,
+    joypad: Joypad,
     cartridge: Cartridge,
     wram: [Byte; WRAM.size],
     hram: [Byte; HRAM.size],
@@ -27,8 +36,12 @@ pub struct MMU {
 impl MMU {
     pub fn new() -> Self {
         MMU {
-            // cpu: CPU::new(),
             ppu: PPU::new(),
+            apu: APU::new(),
+            interrupt: InterruptController::new(),
+            timer: Timer::new(),
+            dma: OamDMA::new(),
+            joypad: Joypad::new(),
             cartridge: Cartridge::eject(),
             wram: [DEFAULT_BYTE; WRAM.size],
             hram: [DEFAULT_BYTE; HRAM.size],
@@ -36,18 +49,46 @@ impl MMU {
         }
     }
 
-    // pub fn get_cpu(&mut self) -> &mut CPU {
-    //     &mut self.cpu
-    // }
-
     pub fn get_ppu(&mut self) -> &mut PPU {
         &mut self.ppu
     }
 
+    pub fn get_apu(&mut self) -> &mut APU {
+        &mut self.apu
+    }
+
     pub fn get_cartridge(&mut self) -> &mut Cartridge {
         &mut self.cartridge
     }
 
+    pub fn get_interrupt(&mut self) -> &mut InterruptController {
+        &mut self.interrupt
+    }
+
+    pub fn get_timer(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+
+    pub fn get_dma(&self) -> &OamDMA {
+        &self.dma
+    }
+
+    pub fn press_button(&mut self, button: Button) {
+        self.joypad.press(button, &mut self.interrupt);
+    }
+
+    pub fn release_button(&mut self, button: Button) {
+        self.joypad.release(button);
+    }
+
+    pub fn request_interrupt(&mut self, interrupt: Interrupt) {
+        self.interrupt.request(interrupt);
+    }
+
+    pub fn has_pending_interrupt(&self) -> bool {
+        self.interrupt.has_pending()
+    }
+
     fn read_wram(&self, address: Address) -> Byte {
         self.wram[address - WRAM.start]
     }
@@ -65,14 +106,103 @@ impl MMU {
     }
 
     fn read_io(&self, address: Address) -> Byte {
-        self.io[address - IO.start]
+        if address == P1.offset {
+            self.joypad.read_register()
+        } else if address == DIV.offset {
+            self.timer.read_div()
+        } else if address == TIMA.offset {
+            self.timer.read_tima()
+        } else if address == TMA.offset {
+            self.timer.read_tma()
+        } else if address == TAC.offset {
+            self.timer.read_tac()
+        } else if address == IF.offset {
+            self.interrupt.read_flag()
+        } else if address >= AUDIO.start && address <= AUDIO.end {
+            self.apu.read_register(address)
+        } else if address == LCDC.offset {
+            self.ppu.read_lcdc()
+        } else if address == STAT.offset {
+            self.ppu.read_stat()
+        } else if address == SCY.offset {
+            self.ppu.read_scy()
+        } else if address == SCX.offset {
+            self.ppu.read_scx()
+        } else if address == LY.offset {
+            self.ppu.get_ly()
+        } else if address == LYC.offset {
+            self.ppu.read_lyc()
+        } else if address == DMA.offset {
+            self.dma.read_register()
+        } else if address == BGP.offset {
+            self.ppu.read_bgp()
+        } else if address == OBP0.offset {
+            self.ppu.read_obp0()
+        } else if address == OBP1.offset {
+            self.ppu.read_obp1()
+        } else if address == WY.offset {
+            self.ppu.read_wy()
+        } else if address == WX.offset {
+            self.ppu.read_wx()
+        } else {
+            self.io[address - IO.start]
+        }
     }
 
     fn write_io(&mut self, address: Address, value: Byte) {
-        self.io[address - IO.start] = value;
+        if address == P1.offset {
+            self.joypad.write_register(value, &mut self.interrupt);
+        } else if address == DIV.offset {
+            self.timer.write_div();
+        } else if address == TIMA.offset {
+            self.timer.write_tima(value);
+        } else if address == TMA.offset {
+            self.timer.write_tma(value);
+        } else if address == TAC.offset {
+            self.timer.write_tac(value);
+        } else if address == IF.offset {
+            self.interrupt.write_flag(value);
+        } else if address >= AUDIO.start && address <= AUDIO.end {
+            self.apu.write_register(address, value);
+        } else if address == LCDC.offset {
+            self.ppu.write_lcdc(value);
+        } else if address == STAT.offset {
+            self.ppu.write_stat(value);
+        } else if address == SCY.offset {
+            self.ppu.write_scy(value);
+        } else if address == SCX.offset {
+            self.ppu.write_scx(value);
+        } else if address == LY.offset {
+            // Read-only
+        } else if address == LYC.offset {
+            self.ppu.write_lyc(value);
+        } else if address == DMA.offset {
+            self.dma.start(value);
+        } else if address == BGP.offset {
+            self.ppu.write_bgp(value);
+        } else if address == OBP0.offset {
+            self.ppu.write_obp0(value);
+        } else if address == OBP1.offset {
+            self.ppu.write_obp1(value);
+        } else if address == WY.offset {
+            self.ppu.write_wy(value);
+        } else if address == WX.offset {
+            self.ppu.write_wx(value);
+        } else {
+            self.io[address - IO.start] = value;
+        }
     }
 
     pub fn read_memory(&self, address: Address) -> Byte {
+        // During OAM DMA the CPU can only reach HRAM
+        if self.dma.is_active() && !(address >= HRAM.start && address <= HRAM.end) {
+            return 0xFF;
+        }
+
+        self.read_bus(address)
+    }
+
+    fn read_bus(&self, address: Address) -> Byte {
         if address >= ROM.start && address <= ROM.end {
             self.cartridge.read_rom(address)
         } else if address >= VRAM.start && address <= VRAM.end {
@@ -92,19 +222,19 @@ impl MMU {
         } else if address >= HRAM.start && address <= HRAM.end {
             self.read_hram(address)
         } else if address >= IE_REGISTER.start && address <= IE_REGISTER.end {
-            0xFF
+            self.interrupt.read_enable()
         } else {
             panic!("Invalid memory read at address: 0x{:04X}", address);
         }
     }
 
     pub fn write_memory(&mut self, address: Address, value: Byte) {
+        if self.dma.is_active() && !(address >= HRAM.start && address <= HRAM.end) {
+            return;
+        }
+
         if address >= ROM.start && address <= ROM.end {
-            println!(
-                "Tentative d'écriture en ROM à 0x{:04X} avec valeur 0x{:02X}",
-                address, value
-            );
-            // panic!("Cannot write to ROM address: 0x{:04X}", address);
+            self.cartridge.write_rom(address, value);
         } else if address >= VRAM.start && address <= VRAM.end {
             self.ppu.write_vram(address, value);
         } else if address >= RAM.start && address <= RAM.end {
@@ -122,21 +252,32 @@ impl MMU {
         } else if address >= HRAM.start && address <= HRAM.end {
             self.write_hram(address, value);
         } else if address >= IE_REGISTER.start && address <= IE_REGISTER.end {
-            // Do nothing
+            self.interrupt.write_enable(value);
         } else {
             panic!("Invalid memory write at address: 0x{:04X}", address);
         }
     }
 
     pub fn set_cartridge(&mut self, cartridge: Cartridge) {
-        if !cartridge.is_valid() {
-            panic!("Invalid cartridge inserted!");
-        }
+        let force: bool = self.cartridge.has_rtc();
+        self.cartridge.flush_save(force);
         self.cartridge = cartridge;
     }
 
-    pub fn on_frame(&mut self) {
-        self.boot_update_animation();
+    pub fn tick(&mut self, cycles: Cycles) {
+        self.timer.tick(cycles, &mut self.interrupt);
+        self.ppu.tick(cycles, &mut self.interrupt);
+        self.apu.tick(cycles);
+        self.tick_dma(cycles);
+    }
+
+    fn tick_dma(&mut self, cycles: Cycles) {
+        self.dma.tick(cycles);
+
+        while let Some((source, destination)) = self.dma.next_transfer() {
+            let value: Byte = self.read_bus(source);
+            self.ppu.write_oam(destination, value);
+        }
     }
 
     pub fn delay_frames(frames: u8) -> bool {
@@ -175,8 +316,8 @@ impl MMU {
 
     pub fn get_screen_scroll(&self) -> (Address, Address) {
         (
-            self.read_io(0xFF42) as Address,
-            self.read_io(0xFF43) as Address,
+            self.read_io(SCY.offset) as Address,
+            self.read_io(SCX.offset) as Address,
         )
     }
 }
@@ -1,2 +1,3 @@
 pub mod boot_rom;
+pub mod dma;
 pub mod mmu;
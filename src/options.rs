@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+/// Command-line options, parsed before GTK sees the arguments.
+#[derive(Clone, Default)]
+pub struct Options {
+    pub record_audio: Option<PathBuf>,
+    pub record_channels: bool,
+    pub record_vgm: Option<PathBuf>,
+}
+
+impl Options {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let mut options: Options = Options::default();
+        let mut args = args.skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record-audio" => match args.next() {
+                    Some(path) => options.record_audio = Some(PathBuf::from(path)),
+                    None => return Err("--record-audio attend un fichier .wav".to_string()),
+                },
+                "--record-channels" => options.record_channels = true,
+                "--record-vgm" => match args.next() {
+                    Some(path) => options.record_vgm = Some(PathBuf::from(path)),
+                    None => return Err("--record-vgm attend un fichier .vgm".to_string()),
+                },
+                other => return Err(format!("option inconnue : {}", other)),
+            }
+        }
+
+        if options.record_channels && options.record_audio.is_none() {
+            return Err("--record-channels nécessite --record-audio".to_string());
+        }
+        Ok(options)
+    }
+
+    pub fn get_usage() -> &'static str {
+        "Usage : gbrs [--record-audio <fichier.wav> [--record-channels]] [--record-vgm <fichier.vgm>]"
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     common::{
         address::TILE_SET,
+        constant::{SCREEN_HEIGHT, SCREEN_WIDTH},
         types::{Address, Byte},
     },
     ppu::{
@@ -38,41 +39,10 @@ impl LCD {
         let context = Context::new(&surface).unwrap();
         context.scale(self.scale as f64, self.scale as f64);
 
-        let palette: Palette = Palette::from_colors(
-            Colors::White,
-            Colors::LightGray,
-            Colors::DarkGray,
-            Colors::Black,
-        );
-
-        let (scx, scy) = ppu.get_screen_scroll();
-
-        for screen_y in 0..self.screen_height {
-            for screen_x in 0..self.screen_width {
-                let mut map_x: i32 = scx as i32 + screen_x;
-                let mut map_y: i32 = scy as i32 + screen_y;
-
-                if map_x >= 256 {
-                    map_x -= 256;
-                } else if map_x < 0 {
-                    map_x += 256;
-                }
-
-                if map_y >= 256 {
-                    map_y -= 256;
-                } else if map_y < 0 {
-                    map_y += 256;
-                }
-
-                let tile_x: Address = (map_x / 8) as Address;
-                let tile_y: Address = (map_y / 8) as Address;
-                let pixel_x: Address = (map_x % 8) as Address;
-                let pixel_y: Address = (map_y % 8) as Address;
+        let frame_buffer: &[[Colors; SCREEN_WIDTH]; SCREEN_HEIGHT] = ppu.get_frame_buffer();
 
-                let tile_id: Address = ppu.get_tile_id(tile_x, tile_y);
-                let tile: Tile = ppu.get_tile(tile_id);
-                let color: Byte = tile.get_pixel(pixel_x, pixel_y);
-                let color: Colors = palette.get_color(color);
+        for (screen_y, row) in frame_buffer.iter().enumerate() {
+            for (screen_x, color) in row.iter().enumerate() {
                 let (r, g, b) = color.to_tuple(false);
 
                 context.set_source_rgb(r, g, b);
@@ -102,7 +72,7 @@ impl LCD {
         for y in 0..32 {
             for x in 0..32 {
                 let tile_id: Address = ppu.get_tile_id(x, y);
-                let tile: Tile = Tile::from_address(ppu, tile_id);
+                let tile: Tile = ppu.get_bg_tile(tile_id);
 
                 for ty in 0..8 {
                     for tx in 0..8 {
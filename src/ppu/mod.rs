@@ -1,4 +1,5 @@
 pub mod lcd;
 pub mod palette;
 pub mod ppu;
+pub mod sprite;
 pub mod tile;
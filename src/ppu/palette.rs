@@ -15,6 +15,15 @@ pub struct Palette {
 }
 
 impl Colors {
+    pub fn from_shade(shade: Byte) -> Colors {
+        match shade & 0x03 {
+            0 => Colors::White,
+            1 => Colors::LightGray,
+            2 => Colors::DarkGray,
+            _ => Colors::Black,
+        }
+    }
+
     pub fn to_tuple(&self, green_filter: bool) -> (f64, f64, f64) {
         if green_filter {
             return match self {
@@ -71,4 +80,13 @@ impl Palette {
         palette.set_color(3, color_idx_3);
         palette
     }
+
+    pub fn from_register(value: Byte) -> Palette {
+        let mut palette: Palette = Palette::new();
+        for index in 0..4 {
+            let shade: Byte = (value >> (index * 2)) & 0x03;
+            palette.set_color(index, Colors::from_shade(shade));
+        }
+        palette
+    }
 }
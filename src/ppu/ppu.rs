@@ -1,24 +1,65 @@
 use crate::{
     common::{
-        address::{OAM, TILE_MAP, TILE_SET, VRAM},
-        constant::DEFAULT_BYTE,
-        types::{Address, Byte},
+        address::{
+            OAM, TILE_MAP, TILE_MAP_HIGH, TILE_MAP_LOW, TILE_SET, TILE_SET_SIGNED_BASE, VRAM,
+        },
+        constant::{
+            DEFAULT_BYTE, DOTS_PER_SCANLINE, DRAWING_DOTS, MAX_SPRITES_PER_LINE, OAM_SCAN_DOTS,
+            SCANLINES_PER_FRAME, SCREEN_HEIGHT, SCREEN_WIDTH, SPRITE_COUNT, VBLANK_START_LINE,
+        },
+        types::{Address, Byte, Cycles},
+    },
+    interrupt::interrupt::{Interrupt, InterruptController},
+    ppu::{
+        palette::{Colors, Palette},
+        sprite::Sprite,
+        tile::Tile,
     },
-    ppu::tile::Tile,
 };
 
-enum AddressingMethod {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressingMethod {
     Method8000,
     Method8800,
 }
 
+impl AddressingMethod {
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            AddressingMethod::Method8000 => "0x8000 (non signé)",
+            AddressingMethod::Method8800 => "0x8800 (signé)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    HBlank = 0,
+    VBlank = 1,
+    OAMScan = 2,
+    Drawing = 3,
+}
+
 pub struct PPU {
     vram: [Byte; VRAM.size],
     oam: [Byte; OAM.size],
+    frame_buffer: [[Colors; SCREEN_WIDTH]; SCREEN_HEIGHT],
+    lcdc: Byte,
+    stat: Byte,
     scx: Byte,
     scy: Byte,
     wx: Byte,
     wy: Byte,
+    window_line: Byte,
+    window_triggered: bool,
+    ly: Byte,
+    lyc: Byte,
+    bgp: Byte,
+    obp0: Byte,
+    obp1: Byte,
+    mode: Mode,
+    dots: Cycles,
+    stat_line: bool,
 }
 
 impl PPU {
@@ -26,13 +67,406 @@ impl PPU {
         PPU {
             vram: [DEFAULT_BYTE; VRAM.size],
             oam: [DEFAULT_BYTE; OAM.size],
+            frame_buffer: [[Colors::White; SCREEN_WIDTH]; SCREEN_HEIGHT],
+            lcdc: DEFAULT_BYTE,
+            stat: DEFAULT_BYTE,
             scx: DEFAULT_BYTE,
             scy: DEFAULT_BYTE,
             wx: DEFAULT_BYTE,
             wy: DEFAULT_BYTE,
+            window_line: DEFAULT_BYTE,
+            window_triggered: false,
+            ly: DEFAULT_BYTE,
+            lyc: DEFAULT_BYTE,
+            bgp: DEFAULT_BYTE,
+            obp0: DEFAULT_BYTE,
+            obp1: DEFAULT_BYTE,
+            mode: Mode::HBlank,
+            dots: 0,
+            stat_line: false,
+        }
+    }
+
+    pub fn tick(&mut self, cycles: Cycles, interrupt: &mut InterruptController) {
+        if !self.is_lcd_enabled() {
+            return;
+        }
+
+        self.update_stat_line(interrupt);
+
+        for _ in 0..cycles {
+            self.step(interrupt);
+        }
+    }
+
+    fn step(&mut self, interrupt: &mut InterruptController) {
+        self.dots += 1;
+
+        match self.mode {
+            Mode::OAMScan => {
+                if self.dots == OAM_SCAN_DOTS {
+                    self.set_mode(Mode::Drawing, interrupt);
+                }
+            }
+            Mode::Drawing => {
+                if self.dots == OAM_SCAN_DOTS + DRAWING_DOTS {
+                    self.render_scanline();
+                    self.set_mode(Mode::HBlank, interrupt);
+                }
+            }
+            Mode::HBlank => {
+                if self.dots == DOTS_PER_SCANLINE {
+                    self.dots = 0;
+                    self.set_ly(self.ly + 1, interrupt);
+
+                    if self.ly == VBLANK_START_LINE {
+                        self.set_mode(Mode::VBlank, interrupt);
+                        interrupt.request(Interrupt::VBlank);
+                    } else {
+                        self.set_mode(Mode::OAMScan, interrupt);
+                    }
+                }
+            }
+            Mode::VBlank => {
+                if self.dots == DOTS_PER_SCANLINE {
+                    self.dots = 0;
+
+                    if self.ly + 1 == SCANLINES_PER_FRAME {
+                        self.reset_window();
+                        self.set_ly(0, interrupt);
+                        self.set_mode(Mode::OAMScan, interrupt);
+                    } else {
+                        self.set_ly(self.ly + 1, interrupt);
+                    }
+                }
+            }
+        }
+    }
+
+    fn set_mode(&mut self, mode: Mode, interrupt: &mut InterruptController) {
+        self.mode = mode;
+        self.update_stat_line(interrupt);
+    }
+
+    fn set_ly(&mut self, ly: Byte, interrupt: &mut InterruptController) {
+        self.ly = ly;
+        self.update_stat_line(interrupt);
+    }
+
+    fn update_stat_line(&mut self, interrupt: &mut InterruptController) {
+        let lyc_source: bool = (self.stat & (1 << 6)) != 0 && self.ly == self.lyc;
+        let mode_source: bool = match self.mode {
+            Mode::HBlank => (self.stat & (1 << 3)) != 0,
+            Mode::VBlank => (self.stat & (1 << 4)) != 0,
+            Mode::OAMScan => (self.stat & (1 << 5)) != 0,
+            Mode::Drawing => false,
+        };
+        let stat_line: bool = lyc_source || mode_source;
+
+        // The STAT interrupt only fires on a rising edge of the combined line
+        if stat_line && !self.stat_line {
+            interrupt.request(Interrupt::LCD);
+        }
+        self.stat_line = stat_line;
+    }
+
+    fn render_scanline(&mut self) {
+        let screen_y: usize = self.ly as usize;
+        if screen_y >= SCREEN_HEIGHT {
+            return;
+        }
+
+        let mut bg_line: [Byte; SCREEN_WIDTH] = [DEFAULT_BYTE; SCREEN_WIDTH];
+        self.render_background_line(&mut bg_line);
+        self.render_window_line(&mut bg_line);
+
+        // Palettes are decoded per scanline so mid-frame register writes take effect
+        let bg_palette: Palette = Palette::from_register(self.bgp);
+        let mut line: [Colors; SCREEN_WIDTH] = [Colors::White; SCREEN_WIDTH];
+        // With LCDC.0 off the DMG shows plain white whatever BGP says, while
+        // sprites still see color 0 behind them
+        if (self.lcdc & (1 << 0)) != 0 {
+            for (pixel, &color) in line.iter_mut().zip(bg_line.iter()) {
+                *pixel = bg_palette.get_color(color);
+            }
+        }
+
+        if (self.lcdc & (1 << 1)) != 0 {
+            self.render_sprite_line(&bg_line, &mut line);
+        }
+
+        self.frame_buffer[screen_y] = line;
+    }
+
+    fn render_background_line(&self, bg_line: &mut [Byte; SCREEN_WIDTH]) {
+        if (self.lcdc & (1 << 0)) == 0 {
+            return;
+        }
+
+        let screen_y: Address = self.ly as Address;
+        for (screen_x, pixel) in bg_line.iter_mut().enumerate() {
+            let map_x: Address = (self.scx as Address + screen_x) % 256;
+            let map_y: Address = (self.scy as Address + screen_y) % 256;
+
+            let tile_id: Address = self.get_tile_id(map_x / 8, map_y / 8);
+            let tile: Tile = self.get_bg_tile(tile_id);
+            *pixel = tile.get_pixel(map_x % 8, map_y % 8);
+        }
+    }
+
+    fn render_window_line(&mut self, bg_line: &mut [Byte; SCREEN_WIDTH]) {
+        // The window starts on the first line where LY matches WY and stays
+        // armed for the rest of the frame, even if WY changes afterwards
+        if self.ly == self.wy {
+            self.window_triggered = true;
+        }
+
+        let window_enabled: bool = (self.lcdc & (1 << 0)) != 0 && (self.lcdc & (1 << 5)) != 0;
+        if !window_enabled || !self.window_triggered || self.wx > 166 {
+            return;
+        }
+
+        let map: Address = self.get_window_tile_map();
+        // WX holds the window position plus 7, lower values crop its left edge
+        let window_start: i16 = self.wx as i16 - 7;
+        let window_y: Address = self.window_line as Address;
+
+        for (screen_x, pixel) in bg_line.iter_mut().enumerate() {
+            let window_x: i16 = screen_x as i16 - window_start;
+            if window_x < 0 {
+                continue;
+            }
+
+            let window_x: Address = window_x as Address;
+            let tile_id: Address = self.get_map_tile_id(map, window_x / 8, window_y / 8);
+            let tile: Tile = self.get_bg_tile(tile_id);
+            *pixel = tile.get_pixel(window_x % 8, window_y % 8);
+        }
+
+        // The internal counter only advances on lines where the window was drawn
+        self.window_line = self.window_line.wrapping_add(1);
+    }
+
+    fn reset_window(&mut self) {
+        self.window_line = 0;
+        self.window_triggered = false;
+    }
+
+    fn get_sprite_height(&self) -> i16 {
+        if (self.lcdc & (1 << 2)) != 0 { 16 } else { 8 }
+    }
+
+    fn get_scanline_sprites(&self) -> Vec<Sprite> {
+        let height: i16 = self.get_sprite_height();
+        let ly: i16 = self.ly as i16;
+
+        let mut sprites: Vec<Sprite> = Vec::with_capacity(MAX_SPRITES_PER_LINE);
+        for index in 0..SPRITE_COUNT {
+            let sprite: Sprite = self.get_sprite(index);
+            let top: i16 = sprite.get_y() as i16 - 16;
+
+            if ly >= top && ly < top + height {
+                sprites.push(sprite);
+                if sprites.len() == MAX_SPRITES_PER_LINE {
+                    break;
+                }
+            }
+        }
+
+        // On DMG the sprite with the smallest X wins, then the lowest OAM index
+        sprites.sort_by_key(|sprite| (sprite.get_x(), sprite.get_index()));
+        sprites
+    }
+
+    fn get_sprite_row(&self, sprite: &Sprite) -> [Byte; 8] {
+        let height: i16 = self.get_sprite_height();
+        let mut row: i16 = self.ly as i16 - (sprite.get_y() as i16 - 16);
+        if sprite.is_y_flipped() {
+            row = height - 1 - row;
+        }
+
+        let tile_id: Address = if height == 16 {
+            (sprite.get_tile_id() & 0xFE) as Address + (row / 8) as Address
+        } else {
+            sprite.get_tile_id() as Address
+        };
+        let tile: Tile = self.get_tile(tile_id);
+
+        let mut pixels: [Byte; 8] = [DEFAULT_BYTE; 8];
+        for (x, pixel) in pixels.iter_mut().enumerate() {
+            let column: Address = if sprite.is_x_flipped() { 7 - x } else { x };
+            *pixel = tile.get_pixel(column, (row % 8) as Address);
+        }
+        pixels
+    }
+
+    fn render_sprite_line(
+        &self,
+        bg_line: &[Byte; SCREEN_WIDTH],
+        line: &mut [Colors; SCREEN_WIDTH],
+    ) {
+        let obp0_palette: Palette = Palette::from_register(self.obp0);
+        let obp1_palette: Palette = Palette::from_register(self.obp1);
+        let sprites: Vec<(Sprite, [Byte; 8])> = self
+            .get_scanline_sprites()
+            .into_iter()
+            .map(|sprite| {
+                let pixels: [Byte; 8] = self.get_sprite_row(&sprite);
+                (sprite, pixels)
+            })
+            .collect();
+
+        for screen_x in 0..SCREEN_WIDTH {
+            for (sprite, pixels) in &sprites {
+                let x: i16 = screen_x as i16 - (sprite.get_x() as i16 - 8);
+                if !(0..8).contains(&x) {
+                    continue;
+                }
+
+                let color: Byte = pixels[x as usize];
+                if color == 0 {
+                    continue;
+                }
+
+                if !sprite.has_bg_priority() || bg_line[screen_x] == 0 {
+                    let palette: &Palette = if sprite.uses_obp1() {
+                        &obp1_palette
+                    } else {
+                        &obp0_palette
+                    };
+                    line[screen_x] = palette.get_color(color);
+                }
+                break;
+            }
+        }
+    }
+
+    pub fn get_sprite(&self, index: Address) -> Sprite {
+        let address: Address = index * 4;
+        let bytes: [Byte; 4] = [
+            self.oam[address],
+            self.oam[address + 1],
+            self.oam[address + 2],
+            self.oam[address + 3],
+        ];
+        Sprite::from_bytes(index, bytes)
+    }
+
+    pub fn get_frame_buffer(&self) -> &[[Colors; SCREEN_WIDTH]; SCREEN_HEIGHT] {
+        &self.frame_buffer
+    }
+
+    pub fn is_lcd_enabled(&self) -> bool {
+        (self.lcdc & (1 << 7)) != 0
+    }
+
+    pub fn get_mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn read_lcdc(&self) -> Byte {
+        self.lcdc
+    }
+
+    pub fn write_lcdc(&mut self, value: Byte) {
+        let was_enabled: bool = self.is_lcd_enabled();
+        self.lcdc = value;
+
+        if was_enabled && !self.is_lcd_enabled() {
+            self.ly = 0;
+            self.dots = 0;
+            self.mode = Mode::HBlank;
+            self.frame_buffer = [[Colors::White; SCREEN_WIDTH]; SCREEN_HEIGHT];
+        } else if !was_enabled && self.is_lcd_enabled() {
+            self.reset_window();
+            self.ly = 0;
+            self.dots = 0;
+            self.mode = Mode::OAMScan;
         }
     }
 
+    pub fn read_stat(&self) -> Byte {
+        let coincidence: Byte = if self.ly == self.lyc { 1 << 2 } else { 0 };
+        let mode: Byte = if self.is_lcd_enabled() {
+            self.mode as Byte
+        } else {
+            0
+        };
+        0x80 | (self.stat & 0x78) | coincidence | mode
+    }
+
+    pub fn write_stat(&mut self, value: Byte) {
+        self.stat = value & 0x78;
+    }
+
+    pub fn read_scy(&self) -> Byte {
+        self.scy
+    }
+
+    pub fn write_scy(&mut self, value: Byte) {
+        self.scy = value;
+    }
+
+    pub fn read_scx(&self) -> Byte {
+        self.scx
+    }
+
+    pub fn write_scx(&mut self, value: Byte) {
+        self.scx = value;
+    }
+
+    pub fn get_ly(&self) -> Byte {
+        self.ly
+    }
+
+    pub fn read_lyc(&self) -> Byte {
+        self.lyc
+    }
+
+    pub fn write_lyc(&mut self, value: Byte) {
+        self.lyc = value;
+    }
+
+    pub fn read_wy(&self) -> Byte {
+        self.wy
+    }
+
+    pub fn write_wy(&mut self, value: Byte) {
+        self.wy = value;
+    }
+
+    pub fn read_wx(&self) -> Byte {
+        self.wx
+    }
+
+    pub fn write_wx(&mut self, value: Byte) {
+        self.wx = value;
+    }
+
+    pub fn read_bgp(&self) -> Byte {
+        self.bgp
+    }
+
+    pub fn write_bgp(&mut self, value: Byte) {
+        self.bgp = value;
+    }
+
+    pub fn read_obp0(&self) -> Byte {
+        self.obp0
+    }
+
+    pub fn write_obp0(&mut self, value: Byte) {
+        self.obp0 = value;
+    }
+
+    pub fn read_obp1(&self) -> Byte {
+        self.obp1
+    }
+
+    pub fn write_obp1(&mut self, value: Byte) {
+        self.obp1 = value;
+    }
+
     pub fn read_vram(&self, address: Address) -> Byte {
         self.vram[address - VRAM.start]
     }
@@ -42,11 +476,11 @@ impl PPU {
     }
 
     pub fn read_oam(&self, address: Address) -> Byte {
-        self.oam[address - VRAM.start]
+        self.oam[address - OAM.start]
     }
 
     pub fn write_oam(&mut self, address: Address, value: Byte) {
-        self.oam[address - VRAM.start] = value;
+        self.oam[address - OAM.start] = value;
     }
 
     pub fn reset_vram(&mut self) {
@@ -74,8 +508,54 @@ impl PPU {
         Tile::from_bytes(bytes)
     }
 
+    pub fn get_bg_tile(&self, id: Address) -> Tile {
+        match self.get_addressing_method() {
+            AddressingMethod::Method8000 => self.get_tile(id),
+            AddressingMethod::Method8800 => {
+                // Tile ids are signed offsets around 0x9000 in this mode
+                let offset: isize = (id as Byte as i8) as isize * 16;
+                let address: Address = (TILE_SET_SIGNED_BASE.offset as isize + offset) as Address;
+                let mut bytes: [Byte; 16] = [DEFAULT_BYTE; 16];
+
+                for i in 0..16 {
+                    bytes[i] = self.read_vram(address + i);
+                }
+
+                Tile::from_bytes(bytes)
+            }
+        }
+    }
+
+    pub fn get_addressing_method(&self) -> AddressingMethod {
+        if (self.lcdc & (1 << 4)) != 0 {
+            AddressingMethod::Method8000
+        } else {
+            AddressingMethod::Method8800
+        }
+    }
+
+    pub fn get_bg_tile_map(&self) -> Address {
+        if (self.lcdc & (1 << 3)) != 0 {
+            TILE_MAP_HIGH.offset
+        } else {
+            TILE_MAP_LOW.offset
+        }
+    }
+
+    pub fn get_window_tile_map(&self) -> Address {
+        if (self.lcdc & (1 << 6)) != 0 {
+            TILE_MAP_HIGH.offset
+        } else {
+            TILE_MAP_LOW.offset
+        }
+    }
+
     pub fn get_tile_id(&self, x: Address, y: Address) -> Address {
-        let tile_address: Address = TILE_MAP.start + (y * 32 + x);
+        self.get_map_tile_id(self.get_bg_tile_map(), x, y)
+    }
+
+    fn get_map_tile_id(&self, map: Address, x: Address, y: Address) -> Address {
+        let tile_address: Address = map + (y * 32 + x);
         self.read_vram(tile_address) as Address
     }
 
@@ -0,0 +1,53 @@
+use crate::common::types::{Address, Byte};
+
+pub struct Sprite {
+    y: Byte,
+    x: Byte,
+    tile_id: Byte,
+    attributes: Byte,
+    index: Address,
+}
+
+impl Sprite {
+    pub fn from_bytes(index: Address, bytes: [Byte; 4]) -> Sprite {
+        Sprite {
+            y: bytes[0],
+            x: bytes[1],
+            tile_id: bytes[2],
+            attributes: bytes[3],
+            index,
+        }
+    }
+
+    pub fn get_y(&self) -> Byte {
+        self.y
+    }
+
+    pub fn get_x(&self) -> Byte {
+        self.x
+    }
+
+    pub fn get_tile_id(&self) -> Byte {
+        self.tile_id
+    }
+
+    pub fn get_index(&self) -> Address {
+        self.index
+    }
+
+    pub fn has_bg_priority(&self) -> bool {
+        (self.attributes & (1 << 7)) != 0
+    }
+
+    pub fn is_y_flipped(&self) -> bool {
+        (self.attributes & (1 << 6)) != 0
+    }
+
+    pub fn is_x_flipped(&self) -> bool {
+        (self.attributes & (1 << 5)) != 0
+    }
+
+    pub fn uses_obp1(&self) -> bool {
+        (self.attributes & (1 << 4)) != 0
+    }
+}
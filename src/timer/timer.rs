@@ -0,0 +1,126 @@
+use crate::{
+    common::{
+        constant::{DEFAULT_BYTE, DEFAULT_WORD},
+        types::{Byte, Cycles, Word},
+    },
+    interrupt::interrupt::{Interrupt, InterruptController},
+};
+
+pub struct Timer {
+    system_counter: Word,
+    tima: Byte,
+    tma: Byte,
+    tac: Byte,
+    overflow_pending: bool,
+    reloading: bool,
+}
+
+impl Timer {
+    pub fn new() -> Self {
+        Timer {
+            system_counter: DEFAULT_WORD,
+            tima: DEFAULT_BYTE,
+            tma: DEFAULT_BYTE,
+            tac: DEFAULT_BYTE,
+            overflow_pending: false,
+            reloading: false,
+        }
+    }
+
+    pub fn tick(&mut self, cycles: Cycles, interrupt: &mut InterruptController) {
+        for _ in 0..cycles / 4 {
+            self.step(interrupt);
+        }
+    }
+
+    fn step(&mut self, interrupt: &mut InterruptController) {
+        self.reloading = false;
+
+        // TIMA stays at 0x00 for one M-cycle before being reloaded from TMA
+        if self.overflow_pending {
+            self.overflow_pending = false;
+            self.tima = self.tma;
+            self.reloading = true;
+            interrupt.request(Interrupt::Timer);
+        }
+
+        self.set_system_counter(self.system_counter.wrapping_add(4));
+    }
+
+    fn get_selected_bit(&self) -> Word {
+        match self.tac & 0x03 {
+            0b00 => 1 << 9,
+            0b01 => 1 << 3,
+            0b10 => 1 << 5,
+            _ => 1 << 7,
+        }
+    }
+
+    fn get_timer_signal(&self) -> bool {
+        let is_enabled: bool = (self.tac & 0x04) != 0;
+        is_enabled && (self.system_counter & self.get_selected_bit()) != 0
+    }
+
+    fn set_system_counter(&mut self, value: Word) {
+        let old_signal: bool = self.get_timer_signal();
+        self.system_counter = value;
+        self.on_signal_change(old_signal);
+    }
+
+    fn on_signal_change(&mut self, old_signal: bool) {
+        // TIMA is clocked by the falling edge of the selected counter bit
+        if old_signal && !self.get_timer_signal() {
+            self.increment_tima();
+        }
+    }
+
+    fn increment_tima(&mut self) {
+        let (result, did_overflow) = self.tima.overflowing_add(1);
+        self.tima = result;
+        if did_overflow {
+            self.overflow_pending = true;
+        }
+    }
+
+    pub fn read_div(&self) -> Byte {
+        (self.system_counter >> 8) as Byte
+    }
+
+    pub fn write_div(&mut self) {
+        self.set_system_counter(DEFAULT_WORD);
+    }
+
+    pub fn read_tima(&self) -> Byte {
+        self.tima
+    }
+
+    pub fn write_tima(&mut self, value: Byte) {
+        // A write during the reload cycle is overwritten by TMA
+        if self.reloading {
+            return;
+        }
+        self.tima = value;
+        self.overflow_pending = false;
+    }
+
+    pub fn read_tma(&self) -> Byte {
+        self.tma
+    }
+
+    pub fn write_tma(&mut self, value: Byte) {
+        self.tma = value;
+        if self.reloading {
+            self.tima = value;
+        }
+    }
+
+    pub fn read_tac(&self) -> Byte {
+        self.tac | 0xF8
+    }
+
+    pub fn write_tac(&mut self, value: Byte) {
+        let old_signal: bool = self.get_timer_signal();
+        self.tac = value & 0x07;
+        self.on_signal_change(old_signal);
+    }
+}